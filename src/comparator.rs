@@ -0,0 +1,501 @@
+use super::hashmap::MapLike;
+use super::iter::match_expected_values;
+use super::{AssertionFailure, Spec};
+
+use std::any::type_name;
+use std::borrow::Borrow;
+use std::fmt::Debug;
+
+/// A `Spec` whose equality, containment and map entry assertions use a custom comparator
+/// instead of `PartialEq`.
+///
+/// It is created with [`Spec::using_comparator`].
+pub struct ComparingSpec<'s, S: 's + ?Sized, F> {
+    spec: Spec<'s, S>,
+    comparator: F,
+    comparator_name: String,
+}
+
+impl<'s, S> Spec<'s, S>
+where
+    S: Debug + ?Sized,
+{
+    /// Asserts that the actual value and the expected value are equal according to the provided
+    /// comparator. The value type does not need to implement `PartialEq`.
+    ///
+    /// The comparator is called with the actual value first and the expected value second.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"Hello").is_equal_to_by(&"hello", |a, b| a.eq_ignore_ascii_case(b));
+    /// ```
+    #[track_caller]
+    pub fn is_equal_to_by<E, F>(&mut self, expected: E, comparator: F)
+    where
+        E: Borrow<S>,
+        F: Fn(&S, &S) -> bool,
+    {
+        let comparator_name = comparator_name::<F>();
+        check_equal_by(self, expected.borrow(), comparator, &comparator_name);
+    }
+
+    /// Attaches a custom comparator to the subject. The returned `ComparingSpec` uses it instead
+    /// of `PartialEq` for `is_equal_to`, `is_not_equal_to`, `contains`, `does_not_contain`,
+    /// `contains_all_of`, `contains_entry` and `does_not_contain_entry`, and names it in failure
+    /// messages.
+    ///
+    /// Every one of these assertions calls the comparator with the actual value first and the
+    /// expected value second, so asymmetric comparators (a prefix, a tolerance...) can be used.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// fn eq_ignoring_case(a: &&str, b: &&str) -> bool {
+    ///     a.eq_ignore_ascii_case(b)
+    /// }
+    ///
+    /// let names = vec!["Alice", "Bob"];
+    /// let mut spec = assert_that(&names).using_comparator(eq_ignoring_case);
+    ///
+    /// spec.contains(&"alice");
+    /// spec.contains_all_of(&["BOB", "alice"]);
+    /// ```
+    pub fn using_comparator<F>(&self, comparator: F) -> ComparingSpec<'s, S, F> {
+        ComparingSpec {
+            spec: self.derive(self.subject, self.subject_name),
+            comparator,
+            comparator_name: comparator_name::<F>(),
+        }
+    }
+}
+
+impl<'s, S, F> ComparingSpec<'s, S, F>
+where
+    S: Debug + ?Sized,
+{
+    /// Asserts that the actual value and the expected value are equal according to the
+    /// comparator.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"Hello")
+    ///     .using_comparator(|a: &&str, b: &&str| a.eq_ignore_ascii_case(b))
+    ///     .is_equal_to(&"hello");
+    /// ```
+    #[track_caller]
+    pub fn is_equal_to<E: Borrow<S>>(&mut self, expected: E)
+    where
+        F: Fn(&S, &S) -> bool,
+    {
+        check_equal_by(
+            &mut self.spec,
+            expected.borrow(),
+            &self.comparator,
+            &self.comparator_name,
+        );
+    }
+
+    /// Asserts that the actual value and the expected value are not equal according to the
+    /// comparator.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"Hello")
+    ///     .using_comparator(|a: &&str, b: &&str| a.eq_ignore_ascii_case(b))
+    ///     .is_not_equal_to(&"world");
+    /// ```
+    #[track_caller]
+    pub fn is_not_equal_to<E: Borrow<S>>(&mut self, expected: E)
+    where
+        F: Fn(&S, &S) -> bool,
+    {
+        let subject = self.spec.subject;
+        let borrowed_expected = expected.borrow();
+
+        if (self.comparator)(subject, borrowed_expected) {
+            AssertionFailure::from_spec(&self.spec)
+                .with_expected(format!(
+                    "<{:?}> not equal to <{:?}> using {}",
+                    subject, borrowed_expected, self.comparator_name
+                ))
+                .with_actual("equal".to_string())
+                .fail();
+        }
+    }
+
+    /// Asserts that the iterable subject contains a value equal to the expected one according
+    /// to the comparator.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1, 2, 3];
+    /// assert_that(&test_vec)
+    ///     .using_comparator(|a: &i32, b: &i32| a % 2 == b % 2)
+    ///     .contains(&5);
+    /// ```
+    #[track_caller]
+    pub fn contains<T, E>(&mut self, expected_value: E)
+    where
+        T: 's + Debug,
+        E: Borrow<T>,
+        &'s S: IntoIterator<Item = &'s T>,
+        F: Fn(&T, &T) -> bool,
+    {
+        self.check_contains(expected_value.borrow(), true);
+    }
+
+    /// Asserts that the iterable subject contains no value equal to the expected one according
+    /// to the comparator.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![2, 4];
+    /// assert_that(&test_vec)
+    ///     .using_comparator(|a: &i32, b: &i32| a % 2 == b % 2)
+    ///     .does_not_contain(&5);
+    /// ```
+    #[track_caller]
+    pub fn does_not_contain<T, E>(&mut self, expected_value: E)
+    where
+        T: 's + Debug,
+        E: Borrow<T>,
+        &'s S: IntoIterator<Item = &'s T>,
+        F: Fn(&T, &T) -> bool,
+    {
+        self.check_contains(expected_value.borrow(), false);
+    }
+
+    /// Asserts that the iterable subject contains all of the expected values according to the
+    /// comparator, each one matching a distinct element.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1, 2, 3];
+    /// assert_that(&test_vec)
+    ///     .using_comparator(|a: &i32, b: &i32| a % 2 == b % 2)
+    ///     .contains_all_of(&[5, 7]);
+    /// ```
+    #[track_caller]
+    pub fn contains_all_of<T, E>(&mut self, expected_values_iter: &'s E)
+    where
+        T: 's + Debug,
+        E: ?Sized,
+        &'s S: IntoIterator<Item = &'s T>,
+        &'s E: IntoIterator<Item = &'s T>,
+        F: Fn(&T, &T) -> bool,
+    {
+        let actual_values: Vec<&T> = self.spec.subject.into_iter().collect();
+        let expected_values = expected_values_iter.into_iter();
+
+        let (mut matched_values, mut unmatched_values, _) =
+            match_expected_values(&actual_values, expected_values, |expected, actual| {
+                (self.comparator)(actual, expected)
+            });
+
+        if !unmatched_values.is_empty() {
+            matched_values.append(&mut unmatched_values);
+
+            AssertionFailure::from_spec(&self.spec)
+                .with_expected(format!(
+                    "iterator to contain items <{:?}> using {}",
+                    matched_values, self.comparator_name
+                ))
+                .with_actual(format!("<{:?}>", actual_values))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject map contains the expected key, with a value equal to the
+    /// expected one according to the comparator. The subject type must implement `MapLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashMap;
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("greeting", "Hello");
+    ///
+    /// assert_that(&test_map)
+    ///     .using_comparator(|a: &&str, b: &&str| a.eq_ignore_ascii_case(b))
+    ///     .contains_entry(&"greeting", &"hello");
+    /// ```
+    #[track_caller]
+    pub fn contains_entry<K, V, E, G>(&mut self, expected_key: E, expected_value: G)
+    where
+        K: Debug,
+        V: Debug,
+        E: Borrow<K>,
+        G: Borrow<V>,
+        S: MapLike<K, V>,
+        F: Fn(&V, &V) -> bool,
+    {
+        let subject = self.spec.subject;
+        let borrowed_expected_key = expected_key.borrow();
+        let borrowed_expected_value = expected_value.borrow();
+
+        let expected_message = format!(
+            "{} containing key <{:?}> with value <{:?}> using {}",
+            S::NAME,
+            borrowed_expected_key,
+            borrowed_expected_value,
+            self.comparator_name
+        );
+
+        match subject.get(borrowed_expected_key) {
+            Some(value) if (self.comparator)(value, borrowed_expected_value) => {}
+            Some(value) => {
+                AssertionFailure::from_spec(&self.spec)
+                    .with_expected(expected_message)
+                    .with_actual(format!(
+                        "key <{:?}> with value <{:?}> instead",
+                        borrowed_expected_key, value
+                    ))
+                    .fail();
+            }
+            None => {
+                let subject_keys: Vec<&K> = subject.entries().map(|(key, _)| key).collect();
+
+                AssertionFailure::from_spec(&self.spec)
+                    .with_expected(expected_message)
+                    .with_actual(format!("no matching key, keys are <{:?}>", subject_keys))
+                    .fail();
+            }
+        }
+    }
+
+    /// Asserts that the subject map does not contain the expected key with a value equal to the
+    /// expected one according to the comparator. The subject type must implement `MapLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashMap;
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("greeting", "Hello");
+    ///
+    /// assert_that(&test_map)
+    ///     .using_comparator(|a: &&str, b: &&str| a.eq_ignore_ascii_case(b))
+    ///     .does_not_contain_entry(&"greeting", &"bye");
+    /// ```
+    #[track_caller]
+    pub fn does_not_contain_entry<K, V, E, G>(&mut self, expected_key: E, expected_value: G)
+    where
+        K: Debug,
+        V: Debug,
+        E: Borrow<K>,
+        G: Borrow<V>,
+        S: MapLike<K, V>,
+        F: Fn(&V, &V) -> bool,
+    {
+        let borrowed_expected_key = expected_key.borrow();
+        let borrowed_expected_value = expected_value.borrow();
+
+        if let Some(value) = self.spec.subject.get(borrowed_expected_key) {
+            if (self.comparator)(value, borrowed_expected_value) {
+                AssertionFailure::from_spec(&self.spec)
+                    .with_expected(format!(
+                        "{} to not contain key <{:?}> with value <{:?}> using {}",
+                        S::NAME,
+                        borrowed_expected_key,
+                        borrowed_expected_value,
+                        self.comparator_name
+                    ))
+                    .with_actual(format!(
+                        "key <{:?}> with value <{:?}>",
+                        borrowed_expected_key, value
+                    ))
+                    .fail();
+            }
+        }
+    }
+
+    #[track_caller]
+    fn check_contains<T>(&mut self, expected_value: &T, should_contain: bool)
+    where
+        T: 's + Debug,
+        &'s S: IntoIterator<Item = &'s T>,
+        F: Fn(&T, &T) -> bool,
+    {
+        let actual_values: Vec<&T> = self.spec.subject.into_iter().collect();
+        let contains_value = actual_values
+            .iter()
+            .any(|actual| (self.comparator)(actual, expected_value));
+
+        if contains_value != should_contain {
+            let condition = if should_contain { " " } else { " not " };
+
+            AssertionFailure::from_spec(&self.spec)
+                .with_expected(format!(
+                    "iterator to{}contain <{:?}> using {}",
+                    condition, expected_value, self.comparator_name
+                ))
+                .with_actual(format!("<{:?}>", actual_values))
+                .fail();
+        }
+    }
+}
+
+#[track_caller]
+fn check_equal_by<S, F>(spec: &mut Spec<S>, expected: &S, comparator: F, comparator_name: &str)
+where
+    S: Debug + ?Sized,
+    F: Fn(&S, &S) -> bool,
+{
+    if !comparator(spec.subject, expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("<{:?}> using {}", expected, comparator_name))
+            .with_actual(format!("<{:?}>", spec.subject))
+            .fail();
+    }
+}
+
+/// Names a comparator after the function it was created from, closures being described as a
+/// custom comparator.
+fn comparator_name<F>() -> String {
+    let full_name = type_name::<F>();
+
+    if full_name.contains("{{closure}}") {
+        return "custom comparator".to_string();
+    }
+
+    let path = full_name.split('<').next().unwrap_or(full_name);
+    let name = path.rsplit("::").next().unwrap_or(path);
+
+    format!("comparator <{}>", name)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::needless_borrows_for_generic_args)]
+    use super::super::prelude::*;
+
+    use std::collections::BTreeMap;
+
+    #[derive(Debug)]
+    struct Name(&'static str);
+
+    fn eq_ignoring_case(a: &Name, b: &Name) -> bool {
+        a.0.eq_ignore_ascii_case(b.0)
+    }
+
+    #[test]
+    fn should_not_panic_if_values_are_equal_by_comparator() {
+        assert_that(&Name("Alice")).is_equal_to_by(Name("alice"), eq_ignoring_case);
+        assert_that(&Name("Alice"))
+            .using_comparator(eq_ignoring_case)
+            .is_equal_to(Name("ALICE"));
+        assert_that(&Name("Alice"))
+            .using_comparator(eq_ignoring_case)
+            .is_not_equal_to(Name("Bob"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: <Name(\"Bob\")> using comparator <eq_ignoring_case>\
+                   \n\t but was: <Name(\"Alice\")>"
+    )]
+    fn should_panic_with_comparator_name_if_values_are_not_equal() {
+        assert_that(&Name("Alice")).is_equal_to_by(Name("Bob"), eq_ignoring_case);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <5> using custom comparator\n\t but was: <4>")]
+    fn should_panic_with_generic_name_if_closure_comparator_fails() {
+        assert_that(&4).is_equal_to_by(5, |a, b| a % 2 == b % 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: <Name(\"Alice\")> not equal to <Name(\"ALICE\")> \
+                   using comparator <eq_ignoring_case>\n\t but was: equal"
+    )]
+    fn should_panic_if_values_are_equal_by_comparator_when_not_expected() {
+        assert_that(&Name("Alice"))
+            .using_comparator(eq_ignoring_case)
+            .is_not_equal_to(Name("ALICE"));
+    }
+
+    #[test]
+    fn should_use_comparator_for_containment() {
+        let names = vec![Name("Alice"), Name("Bob"), Name("bob")];
+        let expected = vec![Name("BOB"), Name("BOB")];
+        let mut spec = assert_that(&names).using_comparator(eq_ignoring_case);
+
+        spec.contains(Name("ALICE"));
+        spec.does_not_contain(&Name("Carol"));
+        spec.contains_all_of(&expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain <Name(\"Carol\")> \
+                   using comparator <eq_ignoring_case>\
+                   \n\t but was: <[Name(\"Alice\")]>")]
+    fn should_panic_if_iterable_does_not_contain_by_comparator() {
+        let names = vec![Name("Alice")];
+        assert_that(&names)
+            .using_comparator(eq_ignoring_case)
+            .contains(Name("Carol"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: iterator to contain items <[Name(\"bob\"), Name(\"BOB\")]> \
+                   using comparator <eq_ignoring_case>\
+                   \n\t but was: <[Name(\"Alice\"), Name(\"Bob\")]>"
+    )]
+    fn should_panic_if_iterable_does_not_contain_all_by_comparator() {
+        let names = vec![Name("Alice"), Name("Bob")];
+        let expected = vec![Name("bob"), Name("BOB")];
+
+        assert_that(&names)
+            .using_comparator(eq_ignoring_case)
+            .contains_all_of(&expected);
+    }
+
+    #[test]
+    fn should_use_comparator_for_map_entries() {
+        let test_map: BTreeMap<u8, Name> = [(1, Name("Alice"))].into_iter().collect();
+        let mut spec = assert_that(&test_map).using_comparator(eq_ignoring_case);
+
+        spec.contains_entry(1, Name("alice"));
+        spec.does_not_contain_entry(1, Name("Bob"));
+        spec.does_not_contain_entry(2, Name("alice"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: BTreeMap containing key <1> with value <Name(\"Bob\")> \
+                   using comparator <eq_ignoring_case>\
+                   \n\t but was: key <1> with value <Name(\"Alice\")> instead"
+    )]
+    fn should_panic_if_map_does_not_contain_entry_by_comparator() {
+        let test_map: BTreeMap<u8, Name> = [(1, Name("Alice"))].into_iter().collect();
+
+        assert_that(&test_map)
+            .using_comparator(eq_ignoring_case)
+            .contains_entry(1, Name("Bob"));
+    }
+
+    fn starts_with(actual: &Name, expected: &Name) -> bool {
+        actual.0.starts_with(expected.0)
+    }
+
+    #[test]
+    fn should_pass_actual_value_first_to_comparator() {
+        let names = vec![Name("Alice"), Name("Bob")];
+        let expected = vec![Name("B"), Name("Al")];
+        let test_map: BTreeMap<u8, Name> = [(1, Name("Alice"))].into_iter().collect();
+
+        assert_that(&Name("Alice")).is_equal_to_by(Name("Al"), starts_with);
+
+        let mut spec = assert_that(&Name("Al")).using_comparator(starts_with);
+        spec.is_equal_to(Name("A"));
+        spec.is_not_equal_to(Name("Alice"));
+
+        let mut spec = assert_that(&names).using_comparator(starts_with);
+        spec.contains(Name("Al"));
+        spec.does_not_contain(Name("Alice Cooper"));
+        spec.contains_all_of(&expected);
+
+        let mut spec = assert_that(&test_map).using_comparator(starts_with);
+        spec.contains_entry(1, Name("Al"));
+        spec.does_not_contain_entry(1, Name("Alice Cooper"));
+    }
+}
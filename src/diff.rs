@@ -0,0 +1,354 @@
+//! Rendering of the differences between two strings, appended to string equality failures.
+
+/// Beyond this many characters before the first difference, the start of the line is elided.
+const CONTEXT_BEFORE: usize = 30;
+/// Beyond this many characters after the first difference, the end of the line is elided.
+const CONTEXT_AFTER: usize = 40;
+/// Unchanged lines kept in the line diff before and after each changed block.
+const CONTEXT_LINES: usize = 3;
+/// Beyond this many cells in the table of common lines, the line diff is omitted.
+const MAX_LINE_DIFF_CELLS: usize = 1_000_000;
+
+/// Describes where two strings start to differ, with the index, line and column of the first
+/// differing character and a caret under it. Multi-line strings also get a line diff.
+///
+/// Returns `None` if the strings are equal.
+pub(crate) fn describe_difference(actual: &str, expected: &str) -> Option<String> {
+    if actual == expected {
+        return None;
+    }
+
+    let actual_chars: Vec<char> = actual.chars().collect();
+    let expected_chars: Vec<char> = expected.chars().collect();
+
+    let index = actual_chars
+        .iter()
+        .zip(expected_chars.iter())
+        .take_while(|(actual, expected)| actual == expected)
+        .count();
+
+    let line_start = actual_chars[..index]
+        .iter()
+        .rposition(|c| *c == '\n')
+        .map_or(0, |position| position + 1);
+    let line = actual_chars[..index].iter().filter(|c| **c == '\n').count() + 1;
+    let column = index - line_start + 1;
+
+    let expected_line = line_from(&expected_chars, line_start);
+    let actual_line = line_from(&actual_chars, line_start);
+    let (expected_rendered, caret_offset) = render_line(expected_line, index - line_start);
+    let (actual_rendered, _) = render_line(actual_line, index - line_start);
+
+    let mut description = format!(
+        "\n\tfirst difference at index <{}> (line <{}>, column <{}>):\
+         \n\t\texpected: {}\
+         \n\t\t but was: {}\
+         \n\t\t          {}^",
+        index,
+        line,
+        column,
+        expected_rendered,
+        actual_rendered,
+        " ".repeat(caret_offset)
+    );
+
+    if actual.contains('\n') || expected.contains('\n') {
+        match diff_lines(actual, expected) {
+            Some(diff) => {
+                description.push_str("\n\tline diff (- expected, + actual):");
+                description.push_str(&diff);
+            }
+            None => description.push_str("\n\tline diff omitted, too many lines differ"),
+        }
+    }
+
+    Some(description)
+}
+
+fn line_from(chars: &[char], line_start: usize) -> &[char] {
+    let rest = &chars[line_start.min(chars.len())..];
+    let line_end = rest.iter().position(|c| *c == '\n').unwrap_or(rest.len());
+
+    &rest[..line_end]
+}
+
+/// Renders a line with its control characters escaped, elided around the provided column. Also
+/// returns the rendered width before that column, to place the caret.
+fn render_line(line: &[char], column: usize) -> (String, usize) {
+    let start = column.saturating_sub(CONTEXT_BEFORE);
+    let end = (column + CONTEXT_AFTER).min(line.len());
+
+    let mut rendered = String::new();
+    let mut caret_offset = 0;
+
+    if start > 0 {
+        rendered.push_str("...");
+    }
+
+    for (position, c) in line.iter().enumerate().take(end).skip(start) {
+        if position == column {
+            caret_offset = rendered.chars().count();
+        }
+
+        rendered.extend(c.escape_debug());
+    }
+
+    if column >= end {
+        caret_offset = rendered.chars().count();
+    }
+
+    if end < line.len() {
+        rendered.push_str("...");
+    }
+
+    (rendered, caret_offset)
+}
+
+/// Renders a diff of the lines of both strings, based on their longest common subsequence.
+/// Returns `None` if too many lines differ to compute it.
+fn diff_lines(actual: &str, expected: &str) -> Option<String> {
+    let actual_lines: Vec<&str> = actual.split('\n').collect();
+    let expected_lines: Vec<&str> = expected.split('\n').collect();
+
+    let prefix = actual_lines
+        .iter()
+        .zip(expected_lines.iter())
+        .take_while(|(actual, expected)| actual == expected)
+        .count();
+    let suffix = actual_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(expected_lines[prefix..].iter().rev())
+        .take_while(|(actual, expected)| actual == expected)
+        .count();
+
+    let actual_middle = &actual_lines[prefix..actual_lines.len() - suffix];
+    let expected_middle = &expected_lines[prefix..expected_lines.len() - suffix];
+
+    let cells = (actual_middle.len() + 1).saturating_mul(expected_middle.len() + 1);
+
+    if cells > MAX_LINE_DIFF_CELLS {
+        return None;
+    }
+
+    let mut changes: Vec<(char, &str)> = expected_lines[..prefix]
+        .iter()
+        .map(|line| (' ', *line))
+        .collect();
+    changes.extend(diff_common_subsequence(actual_middle, expected_middle));
+    changes.extend(
+        expected_lines[expected_lines.len() - suffix..]
+            .iter()
+            .map(|line| (' ', *line)),
+    );
+
+    Some(render_changes(&changes))
+}
+
+/// Tags each line with `' '` if it is in both strings, `'-'` if it is only expected and `'+'` if
+/// it is only actual.
+fn diff_common_subsequence<'a>(
+    actual_lines: &[&'a str],
+    expected_lines: &[&'a str],
+) -> Vec<(char, &'a str)> {
+    let mut common = vec![vec![0; actual_lines.len() + 1]; expected_lines.len() + 1];
+
+    for i in (0..expected_lines.len()).rev() {
+        for j in (0..actual_lines.len()).rev() {
+            common[i][j] = if expected_lines[i] == actual_lines[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = vec![];
+    let (mut i, mut j) = (0, 0);
+
+    while i < expected_lines.len() || j < actual_lines.len() {
+        if i < expected_lines.len()
+            && j < actual_lines.len()
+            && expected_lines[i] == actual_lines[j]
+        {
+            changes.push((' ', expected_lines[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual_lines.len()
+            || (i < expected_lines.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            changes.push(('-', expected_lines[i]));
+            i += 1;
+        } else {
+            changes.push(('+', actual_lines[j]));
+            j += 1;
+        }
+    }
+
+    changes
+}
+
+/// Renders the tagged lines, keeping only `CONTEXT_LINES` unchanged lines around each changed
+/// block and counting the others.
+fn render_changes(changes: &[(char, &str)]) -> String {
+    let mut diff = String::new();
+    let mut start = 0;
+
+    while start < changes.len() {
+        let unchanged = changes[start..]
+            .iter()
+            .take_while(|(tag, _)| *tag == ' ')
+            .count();
+
+        if unchanged == 0 {
+            let (tag, line) = changes[start];
+            diff.push_str(&format!("\n\t\t{} {}", tag, line.escape_debug()));
+            start += 1;
+            continue;
+        }
+
+        let end = start + unchanged;
+        let kept_before = if start == 0 { 0 } else { CONTEXT_LINES };
+        let kept_after = if end == changes.len() {
+            0
+        } else {
+            CONTEXT_LINES
+        };
+
+        // Eliding a single line would not make the diff any shorter.
+        if unchanged > kept_before + kept_after + 1 {
+            render_unchanged(&mut diff, &changes[start..start + kept_before]);
+            diff.push_str(&format!(
+                "\n\t\t  ... {} unchanged lines ...",
+                unchanged - kept_before - kept_after
+            ));
+            render_unchanged(&mut diff, &changes[end - kept_after..end]);
+        } else {
+            render_unchanged(&mut diff, &changes[start..end]);
+        }
+
+        start = end;
+    }
+
+    diff
+}
+
+fn render_unchanged(diff: &mut String, changes: &[(char, &str)]) {
+    for (_, line) in changes {
+        diff.push_str(&format!("\n\t\t  {}", line.escape_debug()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_point_at_first_differing_character() {
+        let description = describe_difference("hello wurld", "hello world").unwrap();
+
+        assert_eq!(
+            description,
+            "\n\tfirst difference at index <7> (line <1>, column <8>):\
+             \n\t\texpected: hello world\
+             \n\t\t but was: hello wurld\
+             \n\t\t                 ^"
+        );
+    }
+
+    #[test]
+    fn should_point_after_shorter_string() {
+        let description = describe_difference("hello", "hello!").unwrap();
+
+        assert!(description.contains("index <5> (line <1>, column <6>)"));
+        assert!(description.ends_with("\n\t\t               ^"));
+    }
+
+    #[test]
+    fn should_elide_long_lines_around_difference() {
+        let expected = format!("{}X{}", "a".repeat(50), "b".repeat(50));
+        let actual = format!("{}Y{}", "a".repeat(50), "b".repeat(50));
+        let description = describe_difference(&actual, &expected).unwrap();
+
+        assert!(description.contains(&format!(
+            "expected: ...{}X{}...",
+            "a".repeat(30),
+            "b".repeat(39)
+        )));
+        assert!(description.ends_with(&format!("{}^", " ".repeat(10 + 33))));
+    }
+
+    #[test]
+    fn should_render_line_diff_for_multi_line_strings() {
+        let description = describe_difference("one\ntwo\nthree", "one\n2\nthree\nfour").unwrap();
+
+        assert_eq!(
+            description,
+            "\n\tfirst difference at index <4> (line <2>, column <1>):\
+             \n\t\texpected: 2\
+             \n\t\t but was: two\
+             \n\t\t          ^\
+             \n\tline diff (- expected, + actual):\
+             \n\t\t  one\
+             \n\t\t- 2\
+             \n\t\t+ two\
+             \n\t\t  three\
+             \n\t\t- four"
+        );
+    }
+
+    #[test]
+    fn should_only_keep_context_lines_around_changes_of_large_strings() {
+        let lines: Vec<String> = (0..20_000).map(|i| format!("line {}", i)).collect();
+        let expected = lines.join("\n");
+        let actual = expected.replace("\nline 10000\n", "\nline ten thousand\n");
+        let description = describe_difference(&actual, &expected).unwrap();
+
+        assert!(description.ends_with(
+            "\n\tline diff (- expected, + actual):\
+             \n\t\t  ... 9997 unchanged lines ...\
+             \n\t\t  line 9997\
+             \n\t\t  line 9998\
+             \n\t\t  line 9999\
+             \n\t\t- line 10000\
+             \n\t\t+ line ten thousand\
+             \n\t\t  line 10001\
+             \n\t\t  line 10002\
+             \n\t\t  line 10003\
+             \n\t\t  ... 9996 unchanged lines ..."
+        ));
+        assert!(!description.contains("line 0\n"));
+        assert!(!description.contains("line 19999"));
+    }
+
+    #[test]
+    fn should_elide_unchanged_lines_between_changes() {
+        let expected: Vec<String> = (0..20).map(|i| format!("line {}", i)).collect();
+        let mut actual = expected.clone();
+        actual[2] = "changed 2".to_string();
+        actual[17] = "changed 17".to_string();
+        let description = describe_difference(&actual.join("\n"), &expected.join("\n")).unwrap();
+
+        assert!(description.contains(
+            "\n\t\t+ changed 2\
+             \n\t\t  line 3\
+             \n\t\t  line 4\
+             \n\t\t  line 5\
+             \n\t\t  ... 8 unchanged lines ...\
+             \n\t\t  line 14\
+             \n\t\t  line 15\
+             \n\t\t  line 16\
+             \n\t\t- line 17"
+        ));
+        assert!(description.contains("\n\tline diff (- expected, + actual):\n\t\t  line 0\n"));
+    }
+
+    #[test]
+    fn should_omit_line_diff_if_too_many_lines_differ() {
+        let expected: Vec<String> = (0..2_000).map(|i| format!("expected {}", i)).collect();
+        let actual: Vec<String> = (0..2_000).map(|i| format!("actual {}", i)).collect();
+        let description = describe_difference(&actual.join("\n"), &expected.join("\n")).unwrap();
+
+        assert!(description.ends_with("\n\tline diff omitted, too many lines differ"));
+    }
+}
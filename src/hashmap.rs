@@ -1,27 +1,57 @@
-use super::{AssertionFailure, Spec};
+use super::iter::borrow_all;
+use super::sized::Length;
+use super::{leak, AssertionFailure, Spec};
 
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
+
+#[allow(deprecated)]
+pub use super::sized::HashMapAssertions;
+
+/// A collection of values indexed by keys.
+///
+/// This is implemented for `HashMap` (with any hasher) and `BTreeMap`, and can be implemented
+/// for your own map types to make the map assertions available for them.
+pub trait MapLike<K, V>: Length {
+    fn get(&self, key: &K) -> Option<&V>;
+    fn entries(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_>;
+}
 
-pub trait HashMapAssertions<'s> {
+pub trait KeyHashMapAssertions<'s, K, V> {
+    #[track_caller]
+    fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V>;
+    #[track_caller]
+    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E);
     #[track_caller]
-    fn has_length(&mut self, expected: usize);
+    fn contains_keys<E>(&mut self, expected_keys: E)
+    where
+        E: IntoIterator,
+        E::Item: Borrow<K>;
     #[track_caller]
-    fn is_empty(&mut self);
+    fn does_not_contain_keys<E>(&mut self, expected_keys: E)
+    where
+        E: IntoIterator,
+        E::Item: Borrow<K>;
     #[track_caller]
-    fn is_not_empty(&mut self);
+    fn contains_only_keys<E>(&mut self, expected_keys: E)
+    where
+        K: PartialEq,
+        E: IntoIterator,
+        E::Item: Borrow<K>;
+    fn keys(&mut self) -> Spec<'s, Vec<&'s K>>;
 }
 
-pub trait KeyHashMapAssertions<'s, K: Hash + Eq, V> {
+pub trait ValueHashMapAssertions<'s, K, V> {
+    fn values(&mut self) -> Spec<'s, Vec<&'s V>>;
     #[track_caller]
-    fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V>;
-    #[track_caller]
-    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E);
+    fn all_values_satisfy<F>(&mut self, predicate: F)
+    where
+        F: Fn(&V) -> bool;
 }
 
-pub trait EntryHashMapAssertions<'s, K: Hash + Eq, V: PartialEq> {
+pub trait EntryHashMapAssertions<'s, K, V: PartialEq> {
     #[track_caller]
     fn contains_entry<E: Borrow<K>, F: Borrow<V>>(&mut self, expected_key: E, expected_value: F);
     #[track_caller]
@@ -30,85 +60,133 @@ pub trait EntryHashMapAssertions<'s, K: Hash + Eq, V: PartialEq> {
         expected_key: E,
         expected_value: F,
     );
+    #[track_caller]
+    fn contains_entries<E, F, G>(&mut self, expected_entries: G)
+    where
+        E: Borrow<K>,
+        F: Borrow<V>,
+        G: IntoIterator<Item = (E, F)>;
+    #[track_caller]
+    fn contains_value<E: Borrow<V>>(&mut self, expected_value: E);
+    #[track_caller]
+    fn is_equal_to_map<O: MapLike<K, V>>(&mut self, expected: O);
 }
 
-impl<'s, K, V> HashMapAssertions<'s> for Spec<'s, HashMap<K, V>>
+impl<'s, K, V, M> KeyHashMapAssertions<'s, K, V> for Spec<'s, M>
 where
-    K: Hash + Eq + Debug,
-    V: Debug,
+    K: Debug + 's,
+    V: Debug + 's,
+    M: MapLike<K, V> + ?Sized,
 {
-    /// Asserts that the length of the subject hashmap is equal to the provided length. The subject
-    /// type must be of `HashMap`.
+    /// Asserts that the subject map contains the expected key. The subject type must implement
+    /// `MapLike`.
+    ///
+    /// This will return a new `Spec` containing the associated value if the key is present,
+    /// named after the key (e.g. `map["hello"]`).
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
     /// # use std::collections::HashMap;
     /// let mut test_map = HashMap::new();
-    /// test_map.insert(1, 1);
-    /// test_map.insert(2, 2);
+    /// test_map.insert("hello", "hi");
     ///
-    /// assert_that(&test_map).has_length(2);
+    /// assert_that(&test_map).contains_key(&"hello");
     /// ```
-    fn has_length(&mut self, expected: usize) {
+    fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V> {
         let subject = self.subject;
+        let borrowed_expected_key = expected_key.borrow();
 
-        if subject.len() != expected {
-            AssertionFailure::from_spec(self)
-                .with_expected(format!("hashmap to have length <{}>", expected))
-                .with_actual(format!("<{}>", subject.len()))
-                .fail();
+        if let Some(value) = subject.get(borrowed_expected_key) {
+            let name = self.nested_name("map", &format!("[{:?}]", borrowed_expected_key));
+            return self.derive(value, Some(name));
         }
+
+        let subject_keys: Vec<&K> = subject.entries().map(|(key, _)| key).collect();
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!(
+                "{} to contain key <{:?}>",
+                M::NAME,
+                borrowed_expected_key
+            ))
+            .with_actual(format!("<{:?}>", subject_keys))
+            .fail();
+
+        unreachable!();
     }
 
-    /// Asserts that the subject hashmap is empty. The subject type must be of `HashMap`.
+    /// Asserts that the subject map does not contain the provided key. The subject type must
+    /// implement `MapLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
     /// # use std::collections::HashMap;
-    /// let test_map: HashMap<u8, u8> = HashMap::new();
-    /// assert_that(&test_map).is_empty();
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    ///
+    /// assert_that(&test_map).does_not_contain_key(&"hey");
     /// ```
-    fn is_empty(&mut self) {
+    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E) {
         let subject = self.subject;
+        let borrowed_expected_key = expected_key.borrow();
 
-        if !subject.is_empty() {
+        if subject.get(borrowed_expected_key).is_some() {
             AssertionFailure::from_spec(self)
-                .with_expected("an empty hashmap".to_string())
-                .with_actual(format!("a hashmap with length <{:?}>", subject.len()))
+                .with_expected(format!(
+                    "{} to not contain key <{:?}>",
+                    M::NAME,
+                    borrowed_expected_key
+                ))
+                .with_actual(format!("present in {}", M::NAME))
                 .fail();
         }
     }
 
-    /// Asserts that the subject hashmap is not empty. The subject type must be of `HashMap`.
+    /// Asserts that the subject map contains all of the expected keys. The subject type must
+    /// implement `MapLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
     /// # use std::collections::HashMap;
-    /// let mut test_map: HashMap<u8, u8> = HashMap::new();
-    /// test_map.insert(1, 2);
-    /// assert_that(&test_map).is_not_empty();
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    /// test_map.insert("hey", "ho");
+    ///
+    /// assert_that(&test_map).contains_keys(["hello", "hey"]);
     /// ```
-    fn is_not_empty(&mut self) {
+    fn contains_keys<E>(&mut self, expected_keys: E)
+    where
+        E: IntoIterator,
+        E::Item: Borrow<K>,
+    {
         let subject = self.subject;
+        let expected_keys: Vec<E::Item> = expected_keys.into_iter().collect();
+
+        let missing_keys: Vec<&K> = expected_keys
+            .iter()
+            .map(|key| key.borrow())
+            .filter(|key| subject.get(key).is_none())
+            .collect();
+
+        if !missing_keys.is_empty() {
+            let subject_keys: Vec<&K> = subject.entries().map(|(key, _)| key).collect();
 
-        if subject.is_empty() {
             AssertionFailure::from_spec(self)
-                .with_expected("a non empty hashmap".to_string())
-                .with_actual("an empty hashmap".to_string())
+                .with_expected(format!(
+                    "{} to contain keys <{:?}>",
+                    M::NAME,
+                    borrow_all(&expected_keys)
+                ))
+                .with_actual(format!(
+                    "<{:?}>, missing <{:?}>",
+                    subject_keys, missing_keys
+                ))
                 .fail();
         }
     }
-}
 
-impl<'s, K, V> KeyHashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
-where
-    K: Hash + Eq + Debug,
-    V: Debug,
-{
-    /// Asserts that the subject hashmap contains the expected key. The subject type must be
-    /// of `HashMap`.
-    ///
-    /// This will return a new `Spec` containing the associated value if the key is present.
+    /// Asserts that the subject map contains none of the provided keys. The subject type must
+    /// implement `MapLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
@@ -116,68 +194,163 @@ where
     /// let mut test_map = HashMap::new();
     /// test_map.insert("hello", "hi");
     ///
-    /// assert_that(&test_map).contains_key(&"hello");
+    /// assert_that(&test_map).does_not_contain_keys(["hey", "ho"]);
     /// ```
-    fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V> {
+    fn does_not_contain_keys<E>(&mut self, expected_keys: E)
+    where
+        E: IntoIterator,
+        E::Item: Borrow<K>,
+    {
         let subject = self.subject;
-        let borrowed_expected_key = expected_key.borrow();
+        let expected_keys: Vec<E::Item> = expected_keys.into_iter().collect();
 
-        if let Some(value) = subject.get(borrowed_expected_key) {
-            return Spec {
-                subject: value,
-                subject_name: self.subject_name,
-                location: self.location.clone(),
-                description: self.description,
-            };
-        }
-
-        let subject_keys: Vec<&K> = subject.keys().collect();
+        let present_keys: Vec<&K> = expected_keys
+            .iter()
+            .map(|key| key.borrow())
+            .filter(|key| subject.get(key).is_some())
+            .collect();
 
-        AssertionFailure::from_spec(self)
-            .with_expected(format!(
-                "hashmap to contain key <{:?}>",
-                borrowed_expected_key
-            ))
-            .with_actual(format!("<{:?}>", subject_keys))
-            .fail();
-
-        unreachable!();
+        if !present_keys.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "{} to not contain keys <{:?}>",
+                    M::NAME,
+                    borrow_all(&expected_keys)
+                ))
+                .with_actual(format!("<{:?}> present in {}", present_keys, M::NAME))
+                .fail();
+        }
     }
 
-    /// Asserts that the subject hashmap does not contain the provided key. The subject type must be
-    /// of `HashMap`.
+    /// Asserts that the keys of the subject map are exactly the expected ones, in any order.
+    /// The subject type must implement `MapLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
     /// # use std::collections::HashMap;
     /// let mut test_map = HashMap::new();
     /// test_map.insert("hello", "hi");
+    /// test_map.insert("hey", "ho");
     ///
-    /// assert_that(&test_map).does_not_contain_key(&"hey");
+    /// assert_that(&test_map).contains_only_keys(["hey", "hello"]);
     /// ```
-    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E) {
+    fn contains_only_keys<E>(&mut self, expected_keys: E)
+    where
+        K: PartialEq,
+        E: IntoIterator,
+        E::Item: Borrow<K>,
+    {
         let subject = self.subject;
-        let borrowed_expected_key = expected_key.borrow();
-
-        if subject.get(borrowed_expected_key).is_some() {
+        let expected_keys: Vec<E::Item> = expected_keys.into_iter().collect();
+        let expected_keys = borrow_all(&expected_keys);
+        let subject_keys: Vec<&K> = subject.entries().map(|(key, _)| key).collect();
+
+        let missing_keys: Vec<&K> = expected_keys
+            .iter()
+            .filter(|key| subject.get(key).is_none())
+            .copied()
+            .collect();
+        let unexpected_keys: Vec<&K> = subject_keys
+            .iter()
+            .filter(|key| !expected_keys.contains(key))
+            .copied()
+            .collect();
+
+        if !missing_keys.is_empty() || !unexpected_keys.is_empty() {
             AssertionFailure::from_spec(self)
                 .with_expected(format!(
-                    "hashmap to not contain key <{:?}>",
-                    borrowed_expected_key
+                    "{} to contain only keys <{:?}>",
+                    M::NAME,
+                    expected_keys
+                ))
+                .with_actual(format!(
+                    "<{:?}>, missing <{:?}>, unexpected <{:?}>",
+                    subject_keys, missing_keys, unexpected_keys
                 ))
-                .with_actual("present in hashmap".to_string())
                 .fail();
         }
     }
+
+    /// Returns a new `Spec` over the keys of the subject map, on which any iterable assertion
+    /// can be used. The subject type must implement `MapLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashMap;
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    /// test_map.insert("hey", "ho");
+    ///
+    /// assert_that(&test_map).keys().contains(&"hey");
+    /// ```
+    fn keys(&mut self) -> Spec<'s, Vec<&'s K>> {
+        let keys: Vec<&'s K> = self.subject.entries().map(|(key, _)| key).collect();
+        self.derive(leak(keys), Some(self.nested_name("map", ".keys()")))
+    }
 }
 
-impl<'s, K, V> EntryHashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
+impl<'s, K, V, M> ValueHashMapAssertions<'s, K, V> for Spec<'s, M>
 where
-    K: Hash + Eq + Debug,
+    K: Debug + 's,
+    V: Debug + 's,
+    M: MapLike<K, V> + ?Sized,
+{
+    /// Returns a new `Spec` over the values of the subject map, on which any iterable assertion
+    /// can be used. The subject type must implement `MapLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashMap;
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    /// test_map.insert("hey", "ho");
+    ///
+    /// assert_that(&test_map).values().contains(&"ho");
+    /// ```
+    fn values(&mut self) -> Spec<'s, Vec<&'s V>> {
+        let values: Vec<&'s V> = self.subject.entries().map(|(_, value)| value).collect();
+        self.derive(leak(values), Some(self.nested_name("map", ".values()")))
+    }
+
+    /// Asserts that every value of the subject map satisfies the provided predicate. The
+    /// subject type must implement `MapLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashMap;
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", 1);
+    /// test_map.insert("hey", 2);
+    ///
+    /// assert_that(&test_map).all_values_satisfy(|value| *value > 0);
+    /// ```
+    fn all_values_satisfy<F>(&mut self, predicate: F)
+    where
+        F: Fn(&V) -> bool,
+    {
+        let failing_entries: Vec<(&K, &V)> = self
+            .subject
+            .entries()
+            .filter(|(_, value)| !predicate(value))
+            .collect();
+
+        if !failing_entries.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("all {} values to satisfy predicate", M::NAME))
+                .with_actual(format!("failing entries <{:?}>", failing_entries))
+                .fail();
+        }
+    }
+}
+
+impl<'s, K, V, M> EntryHashMapAssertions<'s, K, V> for Spec<'s, M>
+where
+    K: Debug,
     V: PartialEq + Debug,
+    M: MapLike<K, V> + ?Sized,
 {
-    /// Asserts that the subject hashmap contains the expected key with the expected value.
-    /// The subject type must be of `HashMap`.
+    /// Asserts that the subject map contains the expected key with the expected value.
+    /// The subject type must implement `MapLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
@@ -193,8 +366,10 @@ where
         let borrowed_expected_value = expected_value.borrow();
 
         let expected_message = format!(
-            "hashmap containing key <{:?}> with value <{:?}>",
-            borrowed_expected_key, borrowed_expected_value
+            "{} containing key <{:?}> with value <{:?}>",
+            M::NAME,
+            borrowed_expected_key,
+            borrowed_expected_value
         );
 
         if let Some(value) = subject.get(borrowed_expected_key) {
@@ -213,7 +388,7 @@ where
             unreachable!();
         }
 
-        let subject_keys: Vec<&K> = subject.keys().collect();
+        let subject_keys: Vec<&K> = subject.entries().map(|(key, _)| key).collect();
 
         AssertionFailure::from_spec(self)
             .with_expected(expected_message)
@@ -221,8 +396,8 @@ where
             .fail();
     }
 
-    /// Asserts that the subject hashmap does not contains the provided key and value.
-    /// The subject type must be of `HashMap`.
+    /// Asserts that the subject map does not contains the provided key and value.
+    /// The subject type must implement `MapLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
@@ -248,13 +423,203 @@ where
 
             AssertionFailure::from_spec(self)
                 .with_expected(format!(
-                    "hashmap to not contain key <{:?}> with value <{:?}>",
-                    borrowed_expected_key, borrowed_expected_value
+                    "{} to not contain key <{:?}> with value <{:?}>",
+                    M::NAME,
+                    borrowed_expected_key,
+                    borrowed_expected_value
+                ))
+                .with_actual(format!("present in {}", M::NAME))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject map contains all of the expected entries. The subject type must
+    /// implement `MapLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashMap;
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    /// test_map.insert("hey", "ho");
+    ///
+    /// assert_that(&test_map).contains_entries([("hello", "hi"), ("hey", "ho")]);
+    /// ```
+    fn contains_entries<E, F, G>(&mut self, expected_entries: G)
+    where
+        E: Borrow<K>,
+        F: Borrow<V>,
+        G: IntoIterator<Item = (E, F)>,
+    {
+        let subject = self.subject;
+        let expected_entries: Vec<(E, F)> = expected_entries.into_iter().collect();
+
+        let mut mismatches = vec![];
+
+        for (key, value) in expected_entries.iter() {
+            match subject.get(key.borrow()) {
+                Some(actual) if actual.eq(value.borrow()) => {}
+                Some(actual) => mismatches.push(format!(
+                    "key <{:?}> with value <{:?}> instead of <{:?}>",
+                    key.borrow(),
+                    actual,
+                    value.borrow()
+                )),
+                None => mismatches.push(format!("no matching key <{:?}>", key.borrow())),
+            }
+        }
+
+        if !mismatches.is_empty() {
+            let expected_entries: Vec<(&K, &V)> = expected_entries
+                .iter()
+                .map(|(key, value)| (key.borrow(), value.borrow()))
+                .collect();
+
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "{} containing entries <{:?}>",
+                    M::NAME,
+                    expected_entries
                 ))
-                .with_actual("present in hashmap".to_string())
+                .with_actual(mismatches.join(", "))
                 .fail();
         }
     }
+
+    /// Asserts that the subject map contains the expected value, under any key. The subject type
+    /// must implement `MapLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashMap;
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    ///
+    /// assert_that(&test_map).contains_value(&"hi");
+    /// ```
+    fn contains_value<E: Borrow<V>>(&mut self, expected_value: E) {
+        let subject = self.subject;
+        let borrowed_expected_value = expected_value.borrow();
+
+        if !subject
+            .entries()
+            .any(|(_, value)| value.eq(borrowed_expected_value))
+        {
+            let subject_values: Vec<&V> = subject.entries().map(|(_, value)| value).collect();
+
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "{} containing value <{:?}>",
+                    M::NAME,
+                    borrowed_expected_value
+                ))
+                .with_actual(format!("<{:?}>", subject_values))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject map has the same entries as the expected map. Both types must
+    /// implement `MapLike`.
+    ///
+    /// On failure, the missing keys, the extra keys and the keys with a different value are
+    /// reported rather than both maps.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::{BTreeMap, HashMap};
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    ///
+    /// let mut expected_map = BTreeMap::new();
+    /// expected_map.insert("hello", "hi");
+    ///
+    /// assert_that(&test_map).is_equal_to_map(&expected_map);
+    /// ```
+    fn is_equal_to_map<O: MapLike<K, V>>(&mut self, expected: O) {
+        let subject = self.subject;
+
+        let missing_keys: Vec<&K> = expected
+            .entries()
+            .filter(|(key, _)| subject.get(key).is_none())
+            .map(|(key, _)| key)
+            .collect();
+        let extra_keys: Vec<&K> = subject
+            .entries()
+            .filter(|(key, _)| expected.get(key).is_none())
+            .map(|(key, _)| key)
+            .collect();
+        let differing_values: Vec<String> = subject
+            .entries()
+            .filter_map(|(key, value)| match expected.get(key) {
+                Some(expected_value) if !value.eq(expected_value) => Some(format!(
+                    "key <{:?}> with value <{:?}> instead of <{:?}>",
+                    key, value, expected_value
+                )),
+                _ => None,
+            })
+            .collect();
+
+        if missing_keys.is_empty() && extra_keys.is_empty() && differing_values.is_empty() {
+            return;
+        }
+
+        let mut differences = vec![];
+
+        if !missing_keys.is_empty() {
+            differences.push(format!("missing keys <{:?}>", missing_keys));
+        }
+
+        if !extra_keys.is_empty() {
+            differences.push(format!("extra keys <{:?}>", extra_keys));
+        }
+
+        differences.extend(differing_values);
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("{} equal to expected {}", M::NAME, O::NAME))
+            .with_actual(differences.join(", "))
+            .fail();
+    }
+}
+
+impl<K, V, S> MapLike<K, V> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn entries(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<K, V> MapLike<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    fn get(&self, key: &K) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    fn entries(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<K, V, M> MapLike<K, V> for &M
+where
+    M: MapLike<K, V> + ?Sized,
+{
+    fn get(&self, key: &K) -> Option<&V> {
+        (**self).get(key)
+    }
+
+    fn entries(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        (**self).entries()
+    }
 }
 
 #[cfg(test)]
@@ -262,8 +627,12 @@ mod tests {
     #![allow(clippy::needless_borrows_for_generic_args)]
 
     use super::super::prelude::*;
+    use super::MapLike;
 
-    use std::collections::HashMap;
+    use crate::sized::Length;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeMap, HashMap};
+    use std::hash::BuildHasherDefault;
 
     #[test]
     fn should_not_panic_if_hashmap_length_matches_expected() {
@@ -463,4 +832,230 @@ mod tests {
 
         assert_that(&test_map).does_not_contain_entry(&"hello", &"hi");
     }
+
+    #[test]
+    fn should_support_btree_maps() {
+        let mut test_map = BTreeMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).has_length(1);
+        assert_that(&test_map)
+            .contains_key("hello")
+            .is_equal_to(&"hi");
+        assert_that(&test_map).does_not_contain_key("hey");
+        assert_that(&test_map).contains_entry("hello", "hi");
+        assert_that(&test_map).does_not_contain_entry("hello", "hey");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeMap to contain key <\"hey\">\
+                   \n\t but was: <[\"hello\", \"hi\"]>")]
+    fn should_panic_if_btree_map_does_not_contain_key() {
+        let mut test_map = BTreeMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hi", "hello");
+
+        assert_that(&test_map).contains_key("hey");
+    }
+
+    #[test]
+    fn should_support_hashmaps_with_custom_hasher() {
+        let mut test_map: HashMap<&str, &str, BuildHasherDefault<DefaultHasher>> =
+            HashMap::default();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).has_length(1);
+        assert_that(&test_map).contains_key("hello");
+        assert_that(&test_map).contains_entry("hello", "hi");
+    }
+
+    #[test]
+    fn should_support_referenced_maps() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&&test_map).contains_key("hello");
+        assert_that(&&test_map).contains_entry("hello", "hi");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: pairs containing key <2> with value <\"b\">\
+                   \n\t but was: key <2> with value <\"c\"> instead")]
+    fn should_support_user_map_types() {
+        #[derive(Debug)]
+        struct Pairs(Vec<(u8, &'static str)>);
+
+        impl Length for Pairs {
+            const NAME: &'static str = "pairs";
+
+            fn length(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        impl MapLike<u8, &'static str> for Pairs {
+            fn get(&self, key: &u8) -> Option<&&'static str> {
+                self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+            }
+
+            fn entries(&self) -> Box<dyn Iterator<Item = (&u8, &&'static str)> + '_> {
+                Box::new(self.0.iter().map(|(k, v)| (k, v)))
+            }
+        }
+
+        let pairs = Pairs(vec![(1, "a"), (2, "c")]);
+
+        assert_that(&pairs).has_length(2);
+        assert_that(&pairs).contains_key(1).is_equal_to(&"a");
+        assert_that(&pairs).contains_entry(2, "b");
+    }
+
+    #[test]
+    fn should_not_panic_if_map_contains_all_keys() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+
+        assert_that(&test_map).contains_keys(["a", "c"]);
+        assert_that(&test_map).contains_keys(vec![&"b"]);
+        assert_that(&test_map).does_not_contain_keys(["d", "e"]);
+        assert_that(&test_map).contains_only_keys(["c", "b", "a"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeMap to contain keys <[\"a\", \"d\"]>\
+                   \n\t but was: <[\"a\", \"b\"]>, missing <[\"d\"]>")]
+    fn should_panic_if_map_does_not_contain_all_keys() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_that(&test_map).contains_keys(["a", "d"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: BTreeMap to not contain keys <[\"a\", \"d\"]>\
+                   \n\t but was: <[\"a\"]> present in BTreeMap"
+    )]
+    fn should_panic_if_map_contains_unexpected_keys() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_that(&test_map).does_not_contain_keys(["a", "d"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: BTreeMap to contain only keys <[\"a\", \"d\"]>\
+                   \n\t but was: <[\"a\", \"b\"]>, missing <[\"d\"]>, unexpected <[\"b\"]>"
+    )]
+    fn should_panic_if_map_does_not_contain_only_keys() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_that(&test_map).contains_only_keys(["a", "d"]);
+    }
+
+    #[test]
+    fn should_not_panic_if_map_contains_entries_and_value() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hey", "ho");
+
+        assert_that(&test_map).contains_entries([("hello", "hi"), ("hey", "ho")]);
+        assert_that(&test_map).contains_entries(vec![(&"hey", &"ho")]);
+        assert_that(&test_map).contains_value("ho");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: hashmap containing entries <[(\"hello\", \"hey\"), (\"hi\", \"ho\")]>\
+                   \n\t but was: key <\"hello\"> with value <\"hi\"> instead of <\"hey\">, \
+                   no matching key <\"hi\">"
+    )]
+    fn should_panic_if_map_does_not_contain_entries() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).contains_entries([("hello", "hey"), ("hi", "ho")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap containing value <\"hey\">\
+                   \n\t but was: <[\"hi\"]>")]
+    fn should_panic_if_map_does_not_contain_value() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).contains_value("hey");
+    }
+
+    #[test]
+    fn should_not_panic_if_maps_are_equal() {
+        let test_map: HashMap<&str, u8> = [("a", 1), ("b", 2)].into_iter().collect();
+        let expected: BTreeMap<&str, u8> = [("b", 2), ("a", 1)].into_iter().collect();
+
+        assert_that(&test_map).is_equal_to_map(&expected);
+        assert_that(&expected).is_equal_to_map(&test_map);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeMap equal to expected BTreeMap\
+                   \n\t but was: missing keys <[\"c\"]>, extra keys <[\"d\"]>, \
+                   key <\"a\"> with value <1> instead of <2>")]
+    fn should_panic_with_key_level_diff_if_maps_are_not_equal() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2), ("d", 4)].into_iter().collect();
+        let expected: BTreeMap<&str, u8> = [("a", 2), ("b", 2), ("c", 3)].into_iter().collect();
+
+        assert_that(&test_map).is_equal_to_map(&expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [map[\"hello\"]]\
+                   \n\texpected: <\"ho\">\n\t but was: <\"hi\">")]
+    fn should_name_value_from_contains_key_after_key() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map)
+            .contains_key(&"hello")
+            .is_equal_to(&"ho");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\tfor subject [config[1]]\n\texpected: <\"b\">\n\t but was: <\"a\">"
+    )]
+    fn should_name_value_from_contains_key_after_subject_name() {
+        let test_map: BTreeMap<u8, &str> = [(1, "a")].into_iter().collect();
+
+        assert_that(&test_map)
+            .named("config")
+            .contains_key(1)
+            .is_equal_to(&"b");
+    }
+
+    #[test]
+    fn should_allow_iterable_assertions_on_keys_and_values() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2)].into_iter().collect();
+
+        assert_that(&test_map)
+            .keys()
+            .contains_all_of(&vec![&&"b", &&"a"]);
+        assert_that(&test_map).keys().has_length(2);
+        assert_that(&test_map).values().contains(&2);
+        assert_that(&test_map)
+            .values()
+            .element_at(0)
+            .is_equal_to(&1);
+        assert_that(&test_map).all_values_satisfy(|value| *value < 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [map.values()]\
+                   \n\texpected: iterator to contain <3>\n\t but was: <[1, 2]>")]
+    fn should_name_values_spec_after_subject() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_that(&test_map).values().contains(&3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all BTreeMap values to satisfy predicate\
+                   \n\t but was: failing entries <[(\"b\", 2), (\"c\", 3)]>")]
+    fn should_panic_if_not_all_values_satisfy_predicate() {
+        let test_map: BTreeMap<&str, u8> = [("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+        assert_that(&test_map).all_values_satisfy(|value| *value < 2);
+    }
 }
@@ -1,83 +1,263 @@
+use super::sized::Length;
 use super::{AssertionFailure, Spec};
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::fmt::Debug;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
-pub trait HashSetAssertions<'s> {
+#[allow(deprecated)]
+pub use super::sized::HashSetAssertions;
+
+/// A collection of unique values, on top of its `Length`.
+///
+/// The set algebra assertions walk the `elements` of one set, in any order, and look each of them
+/// up in the other set with `contains`. `is_equal_to_set` relies on values being unique: once no
+/// element of the subject is missing from the expected set, equal lengths mean equal sets.
+pub trait SetLike<T>: Length {
+    fn contains(&self, value: &T) -> bool;
+    fn elements(&self) -> Box<dyn Iterator<Item = &T> + '_>;
+}
+
+pub trait SetAlgebraAssertions<T> {
     #[track_caller]
-    fn has_length(&mut self, expected: usize);
+    fn is_subset_of<O: SetLike<T>>(&mut self, expected: O);
     #[track_caller]
-    fn is_empty(&mut self);
+    fn is_superset_of<O: SetLike<T>>(&mut self, expected: O);
     #[track_caller]
-    fn is_not_empty(&mut self);
+    fn is_disjoint_from<O: SetLike<T>>(&mut self, expected: O);
+    #[track_caller]
+    fn intersects<O: SetLike<T>>(&mut self, expected: O);
+    #[track_caller]
+    fn is_equal_to_set<O: SetLike<T>>(&mut self, expected: O);
 }
 
-impl<'s, K> HashSetAssertions<'s> for Spec<'s, HashSet<K>>
+impl<T, S> SetAlgebraAssertions<T> for Spec<'_, S>
 where
-    K: Hash + Eq + Debug,
+    T: Debug,
+    S: SetLike<T> + ?Sized,
 {
-    /// Asserts that the length of the subject HashSet is equal to the provided length. The subject
-    /// type must be of `HashSet`.
+    /// Asserts that every element of the subject set is also in the expected set. Both types
+    /// must implement `SetLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
     /// # use std::collections::HashSet;
-    /// let mut test_map = HashSet::new();
-    /// test_map.insert(1);
-    /// test_map.insert(2);
+    /// let test_set: HashSet<u8> = [1, 2].into_iter().collect();
+    /// let expected_set: HashSet<u8> = [1, 2, 3].into_iter().collect();
     ///
-    /// assert_that(&test_map).has_length(2);
+    /// assert_that(&test_set).is_subset_of(&expected_set);
     /// ```
-    fn has_length(&mut self, expected: usize) {
-        let subject = self.subject;
+    fn is_subset_of<O: SetLike<T>>(&mut self, expected: O) {
+        let extra_elements = elements_not_in(self.subject, &expected);
 
-        if subject.len() != expected {
+        if !extra_elements.is_empty() {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("HashSet to have length <{}>", expected))
-                .with_actual(format!("<{}>", subject.len()))
+                .with_expected(format!(
+                    "{} to be a subset of <{:?}>",
+                    S::NAME,
+                    elements_of(&expected)
+                ))
+                .with_actual(format!(
+                    "<{:?}>, with extra elements <{:?}>",
+                    elements_of(self.subject),
+                    extra_elements
+                ))
                 .fail();
         }
     }
 
-    /// Asserts that the subject HashSet is empty. The subject type must be of `HashSet`.
+    /// Asserts that every element of the expected set is also in the subject set. Both types
+    /// must implement `SetLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
-    /// # use std::collections::HashSet;
-    /// let test_map: HashSet<u8> = HashSet::new();
-    /// assert_that(&test_map).is_empty();
+    /// # use std::collections::BTreeSet;
+    /// let test_set: BTreeSet<u8> = [1, 2, 3].into_iter().collect();
+    /// let expected_set: BTreeSet<u8> = [1, 2].into_iter().collect();
+    ///
+    /// assert_that(&test_set).is_superset_of(&expected_set);
     /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
+    fn is_superset_of<O: SetLike<T>>(&mut self, expected: O) {
+        let missing_elements = elements_not_in(&expected, self.subject);
 
-        if !subject.is_empty() {
+        if !missing_elements.is_empty() {
             AssertionFailure::from_spec(self)
-                .with_expected("an empty HashSet".to_string())
-                .with_actual(format!("a HashSet with length <{:?}>", subject.len()))
+                .with_expected(format!(
+                    "{} to be a superset of <{:?}>",
+                    S::NAME,
+                    elements_of(&expected)
+                ))
+                .with_actual(format!(
+                    "<{:?}>, with missing elements <{:?}>",
+                    elements_of(self.subject),
+                    missing_elements
+                ))
                 .fail();
         }
     }
 
-    /// Asserts that the subject HashSet is not empty. The subject type must be of `HashSet`.
+    /// Asserts that the subject set has no element in common with the expected set. Both types
+    /// must implement `SetLike`.
     ///
     /// ```rust
     /// # use speculoos::prelude::*;
     /// # use std::collections::HashSet;
-    /// let mut test_map: HashSet<u8> = HashSet::new();
-    /// test_map.insert(42);
-    /// assert_that(&test_map).is_not_empty();
+    /// let test_set: HashSet<u8> = [1, 2].into_iter().collect();
+    /// let expected_set: HashSet<u8> = [3, 4].into_iter().collect();
+    ///
+    /// assert_that(&test_set).is_disjoint_from(&expected_set);
     /// ```
-    fn is_not_empty(&mut self) {
-        let subject = self.subject;
+    fn is_disjoint_from<O: SetLike<T>>(&mut self, expected: O) {
+        let common_elements = elements_in(self.subject, &expected);
+
+        if !common_elements.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "{} to be disjoint from <{:?}>",
+                    S::NAME,
+                    elements_of(&expected)
+                ))
+                .with_actual(format!(
+                    "<{:?}>, with common elements <{:?}>",
+                    elements_of(self.subject),
+                    common_elements
+                ))
+                .fail();
+        }
+    }
 
-        if subject.is_empty() {
+    /// Asserts that the subject set has at least one element in common with the expected set.
+    /// Both types must implement `SetLike`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::HashSet;
+    /// let test_set: HashSet<u8> = [1, 2].into_iter().collect();
+    /// let expected_set: HashSet<u8> = [2, 3].into_iter().collect();
+    ///
+    /// assert_that(&test_set).intersects(&expected_set);
+    /// ```
+    fn intersects<O: SetLike<T>>(&mut self, expected: O) {
+        if elements_in(self.subject, &expected).is_empty() {
             AssertionFailure::from_spec(self)
-                .with_expected("a non empty HashSet".to_string())
-                .with_actual("an empty HashSet".to_string())
+                .with_expected(format!(
+                    "{} to intersect <{:?}>",
+                    S::NAME,
+                    elements_of(&expected)
+                ))
+                .with_actual(format!(
+                    "<{:?}>, with no common elements",
+                    elements_of(self.subject)
+                ))
                 .fail();
         }
     }
+
+    /// Asserts that the subject set has exactly the same elements as the expected set. Both
+    /// types must implement `SetLike`.
+    ///
+    /// On failure, the missing and extra elements are reported rather than both sets.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::{BTreeSet, HashSet};
+    /// let test_set: HashSet<u8> = [1, 2].into_iter().collect();
+    /// let expected_set: BTreeSet<u8> = [2, 1].into_iter().collect();
+    ///
+    /// assert_that(&test_set).is_equal_to_set(&expected_set);
+    /// ```
+    fn is_equal_to_set<O: SetLike<T>>(&mut self, expected: O) {
+        let extra_elements = elements_not_in(self.subject, &expected);
+
+        if extra_elements.is_empty() && self.subject.length() == expected.length() {
+            return;
+        }
+
+        let missing_elements = elements_not_in(&expected, self.subject);
+
+        let mut differences = vec![];
+
+        if !missing_elements.is_empty() {
+            differences.push(format!("missing elements <{:?}>", missing_elements));
+        }
+
+        if !extra_elements.is_empty() {
+            differences.push(format!("extra elements <{:?}>", extra_elements));
+        }
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!(
+                "{} equal to <{:?}>",
+                S::NAME,
+                elements_of(&expected)
+            ))
+            .with_actual(differences.join(", "))
+            .fail();
+    }
+}
+
+impl<T, S> SetLike<T> for HashSet<T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
+
+    fn elements(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T> SetLike<T> for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn contains(&self, value: &T) -> bool {
+        BTreeSet::contains(self, value)
+    }
+
+    fn elements(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T, S> SetLike<T> for &S
+where
+    S: SetLike<T> + ?Sized,
+{
+    fn contains(&self, value: &T) -> bool {
+        (**self).contains(value)
+    }
+
+    fn elements(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        (**self).elements()
+    }
+}
+
+fn elements_of<T, S: SetLike<T> + ?Sized>(set: &S) -> Vec<&T> {
+    set.elements().collect()
+}
+
+fn elements_in<'a, T, S, O>(set: &'a S, other: &O) -> Vec<&'a T>
+where
+    S: SetLike<T> + ?Sized,
+    O: SetLike<T> + ?Sized,
+{
+    set.elements()
+        .filter(|value| other.contains(value))
+        .collect()
+}
+
+fn elements_not_in<'a, T, S, O>(set: &'a S, other: &O) -> Vec<&'a T>
+where
+    S: SetLike<T> + ?Sized,
+    O: SetLike<T> + ?Sized,
+{
+    set.elements()
+        .filter(|value| !other.contains(value))
+        .collect()
 }
 
 #[cfg(test)]
@@ -85,7 +265,7 @@ mod tests {
     #![allow(clippy::needless_borrows_for_generic_args)]
     use super::super::prelude::*;
 
-    use std::collections::HashSet;
+    use std::collections::{BTreeSet, HashSet};
 
     #[test]
     fn should_not_panic_if_hash_set_length_matches_expected() {
@@ -194,4 +374,78 @@ mod tests {
 
         assert_that(&test_map).does_not_contain(&"hello");
     }
+
+    #[test]
+    fn should_not_panic_if_set_relations_hold() {
+        let test_set: HashSet<u8> = [1, 2].into_iter().collect();
+        let larger_set: BTreeSet<u8> = [1, 2, 3].into_iter().collect();
+        let other_set: HashSet<u8> = [3, 4].into_iter().collect();
+
+        assert_that(&test_set).is_subset_of(&larger_set);
+        assert_that(&test_set).is_subset_of(&test_set);
+        assert_that(&larger_set).is_superset_of(&test_set);
+        assert_that(&test_set).is_disjoint_from(&other_set);
+        assert_that(&larger_set).intersects(&other_set);
+        assert_that(&test_set).is_equal_to_set(&[2, 1].into_iter().collect::<BTreeSet<u8>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeSet to be a subset of <[1, 2]>\
+                   \n\t but was: <[1, 3, 4]>, with extra elements <[3, 4]>")]
+    fn should_panic_if_set_is_not_a_subset() {
+        let test_set: BTreeSet<u8> = [1, 3, 4].into_iter().collect();
+        let expected_set: BTreeSet<u8> = [1, 2].into_iter().collect();
+
+        assert_that(&test_set).is_subset_of(&expected_set);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeSet to be a superset of <[1, 2, 5]>\
+                   \n\t but was: <[1, 3]>, with missing elements <[2, 5]>")]
+    fn should_panic_if_set_is_not_a_superset() {
+        let test_set: BTreeSet<u8> = [1, 3].into_iter().collect();
+        let expected_set: BTreeSet<u8> = [1, 2, 5].into_iter().collect();
+
+        assert_that(&test_set).is_superset_of(&expected_set);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeSet to be disjoint from <[2, 3, 4]>\
+                   \n\t but was: <[1, 2, 3]>, with common elements <[2, 3]>")]
+    fn should_panic_if_sets_are_not_disjoint() {
+        let test_set: BTreeSet<u8> = [1, 2, 3].into_iter().collect();
+        let expected_set: BTreeSet<u8> = [2, 3, 4].into_iter().collect();
+
+        assert_that(&test_set).is_disjoint_from(&expected_set);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeSet to intersect <[3, 4]>\
+                   \n\t but was: <[1, 2]>, with no common elements")]
+    fn should_panic_if_sets_do_not_intersect() {
+        let test_set: BTreeSet<u8> = [1, 2].into_iter().collect();
+        let expected_set: BTreeSet<u8> = [3, 4].into_iter().collect();
+
+        assert_that(&test_set).intersects(&expected_set);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeSet equal to <[1, 2, 5]>\
+                   \n\t but was: missing elements <[2, 5]>, extra elements <[3]>")]
+    fn should_panic_if_sets_are_not_equal() {
+        let test_set: BTreeSet<u8> = [1, 3].into_iter().collect();
+        let expected_set: BTreeSet<u8> = [1, 2, 5].into_iter().collect();
+
+        assert_that(&test_set).is_equal_to_set(&expected_set);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: BTreeSet equal to <[1, 2, 3]>\
+                   \n\t but was: missing elements <[3]>")]
+    fn should_panic_if_set_is_a_strict_subset_of_expected_set() {
+        let test_set: BTreeSet<u8> = [1, 2].into_iter().collect();
+        let expected_set: BTreeSet<u8> = [1, 2, 3].into_iter().collect();
+
+        assert_that(&test_set).is_equal_to_set(&expected_set);
+    }
 }
@@ -1,8 +1,13 @@
-use super::{AssertionFailure, Spec};
+use super::sized::Length;
+use super::{leak, AssertionFailure, DescriptiveSpec, Spec};
 
+use std::any::Any;
 use std::borrow::Borrow;
 use std::cmp::PartialEq;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::panic::{self, AssertUnwindSafe};
 
 macro_rules! generate_iter_spec_trait {
     ($trait_name:ident) => {
@@ -22,6 +27,11 @@ macro_rules! generate_iter_spec_trait {
             fn equals_iterator<E>(&mut self, expected_iter: &'s E)
             where
                 E: Iterator<Item = &'s T> + Clone;
+            #[track_caller]
+            fn has_same_elements_as<E>(&mut self, expected: &'s E)
+            where
+                E: ?Sized,
+                &'s E: IntoIterator<Item = &'s T>;
         }
     };
 }
@@ -29,6 +39,17 @@ macro_rules! generate_iter_spec_trait {
 generate_iter_spec_trait!(ContainingIntoIterAssertions);
 generate_iter_spec_trait!(ContainingIteratorAssertions);
 
+pub trait HashedIterAssertions<'s, T: 's>
+where
+    T: Debug + Hash + Eq,
+{
+    #[track_caller]
+    fn has_same_hashed_elements_as<E>(&mut self, expected: &'s E)
+    where
+        E: ?Sized,
+        &'s E: IntoIterator<Item = &'s T>;
+}
+
 pub trait MappingIterAssertions<'s, T: 's>
 where
     T: Debug,
@@ -42,9 +63,117 @@ where
     where
         M: Debug + PartialEq,
         F: Fn(&'s T) -> M;
+    #[track_caller]
+    fn zip_satisfies<E, U, F>(&mut self, expected: &'s E, predicate: F)
+    where
+        E: ?Sized,
+        &'s E: IntoIterator<Item = &'s U>,
+        U: 's + Debug,
+        F: Fn(&'s T, &'s U) -> bool;
+}
+
+pub trait PositionalIterAssertions<'s, T: 's>
+where
+    T: Debug,
+{
+    #[track_caller]
+    fn first(&mut self) -> Spec<'s, T>;
+    #[track_caller]
+    fn last(&mut self) -> Spec<'s, T>;
+    #[track_caller]
+    fn element_at(&mut self, index: usize) -> Spec<'s, T>;
+    #[track_caller]
+    fn single(&mut self) -> Spec<'s, T>;
+}
+
+pub trait GroupingIterAssertions<'s, T: 's> {
+    fn grouped_by<K, F>(&mut self, key_function: F) -> Spec<'s, HashMap<K, Vec<&'s T>>>
+    where
+        K: Hash + Eq + 's,
+        F: Fn(&'s T) -> K;
+    fn partitioned_by<F>(&mut self, predicate: F) -> (Spec<'s, Vec<&'s T>>, Spec<'s, Vec<&'s T>>)
+    where
+        F: Fn(&'s T) -> bool;
+}
+
+pub trait FlatteningIterAssertions<'s, T: 's> {
+    fn flattening<U>(&mut self) -> Spec<'s, Flattened<'s, U>>
+    where
+        U: 's,
+        &'s T: IntoIterator<Item = &'s U>;
+    fn flat_extracting<C, U, F>(&mut self, extracting_function: F) -> Spec<'s, Flattened<'s, U>>
+    where
+        C: 's + ?Sized,
+        U: 's,
+        &'s C: IntoIterator<Item = &'s U>,
+        F: Fn(&'s T) -> &'s C;
+}
+
+/// The elements of a nested collection, flattened in iteration order.
+///
+/// Each element remembers its position in the nested collection, so that the elements picked
+/// with `first`, `last`, `element_at` or `single` are named like `orders[2][1]`.
+pub struct Flattened<'s, T> {
+    elements: Vec<&'s T>,
+    positions: Vec<(usize, usize)>,
+    root_name: &'s str,
+}
+
+/// An assertion over a single-pass iterator, which it owns so that its items can be consumed
+/// without requiring `Clone`.
+///
+/// This is created by the `assert_that_iter` function, or by calling `that_iter` on a
+/// `SpecDescription`.
+#[derive(Debug)]
+pub struct IteratorSpec<'s, I> {
+    pub subject: I,
+    pub subject_name: Option<&'s str>,
+    pub location: Option<String>,
+    pub description: Option<&'s str>,
+}
+
+impl<'s, I> IteratorSpec<'s, I> {
+    /// Associates a name with the subject iterator.
+    ///
+    /// This will be displayed if the assertion fails.
+    pub fn named(mut self, subject_name: &'s str) -> Self {
+        self.subject_name = Some(subject_name);
+
+        self
+    }
+}
+
+impl<'s, I> DescriptiveSpec<'s> for IteratorSpec<'s, I> {
+    fn subject_name(&self) -> Option<&'s str> {
+        self.subject_name
+    }
+
+    fn location(&self) -> Option<String> {
+        self.location.clone()
+    }
+
+    fn description(&self) -> Option<&'s str> {
+        self.description
+    }
 }
 
-impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
+pub trait ConsumingIteratorAssertions<T>
+where
+    T: Debug,
+{
+    #[track_caller]
+    fn yields<E>(&mut self, expected_values: E) -> &mut Self
+    where
+        T: PartialEq,
+        E: IntoIterator,
+        E::Item: Borrow<T>;
+    #[track_caller]
+    fn yields_exactly(&mut self, expected: usize);
+    #[track_caller]
+    fn is_exhausted(&mut self);
+}
+
+impl<'s, T: 's, I: ?Sized> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
 where
     T: Debug + PartialEq,
     &'s I: IntoIterator<Item = &'s T>,
@@ -108,6 +237,26 @@ where
     {
         compare_iterators(self, self.subject.into_iter(), expected_iter.clone());
     }
+
+    /// Asserts that the subject has the same elements as the expected iterable, with the same
+    /// number of occurrences, in any order. The subject must implement `IntoIterator`, and the
+    /// contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// Elements are matched pairwise, which is quadratic: for `Hash + Eq` elements,
+    /// `has_same_hashed_elements_as` counts them instead.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1, 2, 1, 3];
+    /// assert_that(&test_vec).has_same_elements_as(&[3, 1, 2, 1]);
+    /// ```
+    fn has_same_elements_as<E>(&mut self, expected: &'s E)
+    where
+        E: ?Sized,
+        &'s E: IntoIterator<Item = &'s T>,
+    {
+        check_iterator_has_same_elements(self, self.subject.into_iter(), expected.into_iter());
+    }
 }
 
 impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
@@ -174,9 +323,83 @@ where
     {
         compare_iterators(self, self.subject.clone(), expected_iter.clone());
     }
+
+    /// Asserts that the iterable subject has the same elements as the expected iterable, with
+    /// the same number of occurrences, in any order. The subject must implement `Iterator`, and
+    /// the contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1, 2, 1, 3];
+    /// assert_that(&test_vec.iter()).has_same_elements_as(&[3, 1, 2, 1]);
+    /// ```
+    fn has_same_elements_as<E>(&mut self, expected: &'s E)
+    where
+        E: ?Sized,
+        &'s E: IntoIterator<Item = &'s T>,
+    {
+        check_iterator_has_same_elements(self, self.subject.clone(), expected.into_iter());
+    }
+}
+
+impl<'s, T: 's, I: ?Sized> HashedIterAssertions<'s, T> for Spec<'s, I>
+where
+    T: Debug + Hash + Eq,
+    &'s I: IntoIterator<Item = &'s T>,
+{
+    /// Asserts that the subject has the same elements as the expected iterable, with the same
+    /// number of occurrences, in any order. The subject must implement `IntoIterator`, and the
+    /// contained type must implement `Hash`, `Eq` and `Debug`.
+    ///
+    /// This is the counterpart of `has_same_elements_as` counting occurrences in a `HashMap`,
+    /// which keeps large collections linear instead of quadratic.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec!["b", "a", "b"];
+    /// assert_that(&test_vec).has_same_hashed_elements_as(&["a", "b", "b"]);
+    /// ```
+    fn has_same_hashed_elements_as<E>(&mut self, expected: &'s E)
+    where
+        E: ?Sized,
+        &'s E: IntoIterator<Item = &'s T>,
+    {
+        let actual_values: Vec<&T> = self.subject.into_iter().collect();
+        let expected_values: Vec<&T> = expected.into_iter().collect();
+
+        let mut counts: HashMap<&T, (usize, usize)> = HashMap::new();
+        let mut values_in_order = vec![];
+
+        for (value, is_expected) in expected_values
+            .iter()
+            .map(|value| (*value, true))
+            .chain(actual_values.iter().map(|value| (*value, false)))
+        {
+            let count = counts.entry(value).or_insert_with(|| {
+                values_in_order.push(value);
+                (0, 0)
+            });
+
+            if is_expected {
+                count.0 += 1;
+            } else {
+                count.1 += 1;
+            }
+        }
+
+        let differing_counts: Vec<(&T, usize, usize)> = values_in_order
+            .into_iter()
+            .map(|value| (value, counts[value].0, counts[value].1))
+            .filter(|(_, expected_count, actual_count)| expected_count != actual_count)
+            .collect();
+
+        if !differing_counts.is_empty() {
+            fail_with_differing_counts(self, &actual_values, &expected_values, &differing_counts);
+        }
+    }
 }
 
-impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
+impl<'s, T: 's, I: ?Sized> MappingIterAssertions<'s, T> for Spec<'s, I>
 where
     T: Debug,
     &'s I: IntoIterator<Item = &'s T>,
@@ -201,58 +424,603 @@ where
     ///     }
     /// });
     /// ```
-    fn matching_contains<F>(&mut self, matcher: F)
+    fn matching_contains<F>(&mut self, matcher: F)
+    where
+        F: Fn(&'s T) -> bool,
+    {
+        let mut actual = Vec::new();
+        for x in self.subject {
+            if matcher(x) {
+                return;
+            } else {
+                actual.push(x);
+            }
+        }
+        AssertionFailure::from_spec(self).fail_with_message(format!(
+            "expectation failed for iterator with values <{:?}>",
+            actual
+        ));
+    }
+
+    /// Maps the values of the subject before asserting that the mapped subject contains the
+    /// provided value. The subject must implement IntoIterator, and the type of the mapped
+    /// value must implement `PartialEq`.
+    ///
+    /// NOTE: The panic message will refer to the mapped values rather than the values present in
+    /// the original subject.
+    ///
+    /// ```rust
+    /// #[derive(PartialEq, Debug)]
+    /// struct Simple {
+    ///     pub val: usize,
+    /// }
+    ///
+    /// //...
+    /// # use speculoos::prelude::*;
+    /// assert_that(&vec![Simple { val: 1 }, Simple { val: 2 } ]).mapped_contains(|x| x.val, &2);
+    /// ```
+    fn mapped_contains<F, M>(&mut self, mapping_function: F, expected_value: &'s M)
+    where
+        M: Debug + PartialEq,
+        F: Fn(&'s T) -> M,
+    {
+        let subject = self.subject;
+
+        let mapped_vec: Vec<M> = subject.into_iter().map(mapping_function).collect();
+        if mapped_vec.contains(expected_value) {
+            return;
+        }
+
+        panic_unmatched(self, expected_value, mapped_vec, true);
+    }
+
+    /// Walks the subject and the expected collection side by side, asserting that each pair of
+    /// elements satisfies the provided predicate. The predicate can also run nested assertions,
+    /// their failure being reported along with the pair. The subject must implement
+    /// `IntoIterator`, and both contained types must implement `Debug`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// #[derive(Debug)]
+    /// struct Record { id: u32, name: &'static str }
+    ///
+    /// let actual = vec![Record { id: 7, name: "a" }, Record { id: 8, name: "b" }];
+    /// let expected = vec!["a", "b"];
+    ///
+    /// assert_that(&actual).zip_satisfies(&expected, |record, name| record.name == *name);
+    /// assert_that(&actual).zip_satisfies(&expected, |record, name| {
+    ///     assert_that(&record.name).is_equal_to(name);
+    ///     true
+    /// });
+    /// ```
+    fn zip_satisfies<E, U, F>(&mut self, expected: &'s E, predicate: F)
+    where
+        E: ?Sized,
+        &'s E: IntoIterator<Item = &'s U>,
+        U: 's + Debug,
+        F: Fn(&'s T, &'s U) -> bool,
+    {
+        let mut actual_iter = self.subject.into_iter();
+        let mut expected_iter = expected.into_iter();
+
+        let mut read_subject = vec![];
+        let mut read_expected = vec![];
+
+        loop {
+            match (actual_iter.next(), expected_iter.next()) {
+                (Some(actual), Some(expected)) => {
+                    let index = read_subject.len();
+                    let outcome =
+                        panic::catch_unwind(AssertUnwindSafe(|| predicate(actual, expected)));
+
+                    let failure = match outcome {
+                        Ok(true) => None,
+                        Ok(false) => Some("".to_string()),
+                        Err(payload) => Some(format!(
+                            ", failing with:{}",
+                            panic_message(payload.as_ref())
+                        )),
+                    };
+
+                    if let Some(failure) = failure {
+                        AssertionFailure::from_spec(self)
+                            .with_expected(format!(
+                                "pair at index <{}> to satisfy predicate",
+                                index
+                            ))
+                            .with_actual(format!(
+                                "<{:?}> paired with <{:?}>{}",
+                                actual, expected, failure
+                            ))
+                            .fail();
+                    }
+
+                    read_subject.push(actual);
+                    read_expected.push(expected);
+                }
+                (Some(actual), None) => {
+                    AssertionFailure::from_spec(self)
+                        .with_expected(format!("Completed iterator (read <{:?}>)", read_expected))
+                        .with_actual(format!(
+                            "Iterator item of <{:?}> (read <{:?}>)",
+                            actual, read_subject
+                        ))
+                        .fail();
+                }
+                (None, Some(expected)) => {
+                    AssertionFailure::from_spec(self)
+                        .with_expected(format!(
+                            "Iterator item of <{:?}> (read <{:?}>)",
+                            expected, read_expected
+                        ))
+                        .with_actual(format!("Completed iterator (read <{:?}>)", read_subject))
+                        .fail();
+                }
+                (None, None) => break,
+            }
+        }
+    }
+}
+
+impl<'s, T: 's, I: ?Sized> PositionalIterAssertions<'s, T> for Spec<'s, I>
+where
+    T: Debug,
+    &'s I: IntoIterator<Item = &'s T>,
+{
+    /// Asserts that the subject has at least one element. The subject must implement
+    /// `IntoIterator`, and the contained type must implement `Debug`.
+    ///
+    /// This will return a new `Spec` containing the first element, named after its index.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec).first().is_equal_to(&1);
+    /// ```
+    fn first(&mut self) -> Spec<'s, T> {
+        match self.subject.into_iter().next() {
+            Some(value) => self.derive(value, Some(self.nested_name("iterable", "[0]"))),
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected("iterator to have a first element".to_string())
+                    .with_actual("<[]>".to_string())
+                    .fail();
+
+                unreachable!();
+            }
+        }
+    }
+
+    /// Asserts that the subject has at least one element. The subject must implement
+    /// `IntoIterator`, and the contained type must implement `Debug`.
+    ///
+    /// This will return a new `Spec` containing the last element, named after its index.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec).last().is_equal_to(&3);
+    /// ```
+    fn last(&mut self) -> Spec<'s, T> {
+        match self.subject.into_iter().enumerate().last() {
+            Some((index, value)) => self.derive(
+                value,
+                Some(self.nested_name("iterable", &format!("[{}]", index))),
+            ),
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected("iterator to have a last element".to_string())
+                    .with_actual("<[]>".to_string())
+                    .fail();
+
+                unreachable!();
+            }
+        }
+    }
+
+    /// Asserts that the subject has an element at the provided index. The subject must implement
+    /// `IntoIterator`, and the contained type must implement `Debug`.
+    ///
+    /// This will return a new `Spec` containing the element, named after its index.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec).element_at(1).is_equal_to(&2);
+    /// ```
+    fn element_at(&mut self, index: usize) -> Spec<'s, T> {
+        if let Some(value) = self.subject.into_iter().nth(index) {
+            return self.derive(
+                value,
+                Some(self.nested_name("iterable", &format!("[{}]", index))),
+            );
+        }
+
+        let actual: Vec<&T> = self.subject.into_iter().collect();
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("iterator to have an element at index <{}>", index))
+            .with_actual(format!("<{:?}>", actual))
+            .fail();
+
+        unreachable!();
+    }
+
+    /// Asserts that the subject has exactly one element. The subject must implement
+    /// `IntoIterator`, and the contained type must implement `Debug`.
+    ///
+    /// This will return a new `Spec` containing the element.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1];
+    /// assert_that(&test_vec).single().is_equal_to(&1);
+    /// ```
+    fn single(&mut self) -> Spec<'s, T> {
+        let mut subject_iter = self.subject.into_iter();
+
+        if let (Some(value), None) = (subject_iter.next(), subject_iter.next()) {
+            return self.derive(value, Some(self.nested_name("iterable", "[0]")));
+        }
+
+        let actual: Vec<&T> = self.subject.into_iter().collect();
+
+        AssertionFailure::from_spec(self)
+            .with_expected("iterator to have a single element".to_string())
+            .with_actual(format!("<{:?}>", actual))
+            .fail();
+
+        unreachable!();
+    }
+}
+
+impl<'s, T: 's, I: ?Sized> GroupingIterAssertions<'s, T> for Spec<'s, I>
+where
+    &'s I: IntoIterator<Item = &'s T>,
+{
+    /// Groups the elements of the subject by the key returned by the provided function. The
+    /// subject must implement `IntoIterator`.
+    ///
+    /// This will return a new `Spec` containing a `HashMap` from each key to the elements sharing
+    /// it, in iteration order, on which the map assertions can be used.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let words = vec!["apple", "avocado", "banana"];
+    /// let mut groups = assert_that(&words).grouped_by(|word| word.chars().next());
+    ///
+    /// groups.has_length(2);
+    /// groups.contains_key(Some('a')).has_length(2);
+    /// ```
+    fn grouped_by<K, F>(&mut self, key_function: F) -> Spec<'s, HashMap<K, Vec<&'s T>>>
+    where
+        K: Hash + Eq + 's,
+        F: Fn(&'s T) -> K,
+    {
+        let mut groups: HashMap<K, Vec<&'s T>> = HashMap::new();
+
+        for value in self.subject {
+            groups.entry(key_function(value)).or_default().push(value);
+        }
+
+        self.derive(leak(groups), self.subject_name)
+    }
+
+    /// Splits the elements of the subject in two, depending on whether they match the provided
+    /// predicate. The subject must implement `IntoIterator`.
+    ///
+    /// This will return two new `Spec`s, containing the matching elements and the other ones in
+    /// iteration order.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1, 2, 3, 4, 5];
+    /// let (mut even, mut odd) = assert_that(&test_vec).partitioned_by(|value| value % 2 == 0);
+    ///
+    /// even.has_length(2);
+    /// odd.contains(&&5);
+    /// ```
+    fn partitioned_by<F>(&mut self, predicate: F) -> (Spec<'s, Vec<&'s T>>, Spec<'s, Vec<&'s T>>)
+    where
+        F: Fn(&'s T) -> bool,
+    {
+        let (matching, not_matching): (Vec<&'s T>, Vec<&'s T>) =
+            self.subject.into_iter().partition(|value| predicate(value));
+
+        (
+            self.derive(
+                leak(matching),
+                Some(self.nested_name("iterable", " matching predicate")),
+            ),
+            self.derive(
+                leak(not_matching),
+                Some(self.nested_name("iterable", " not matching predicate")),
+            ),
+        )
+    }
+}
+
+impl<'s, T: 's, I: ?Sized> FlatteningIterAssertions<'s, T> for Spec<'s, I>
+where
+    &'s I: IntoIterator<Item = &'s T>,
+{
+    /// Flattens the elements of the subject, which must themselves be iterable. The subject
+    /// must implement `IntoIterator`.
+    ///
+    /// This will return a new `Spec` containing all the inner elements, named like
+    /// `orders flattened`, on which any iterable assertion can be used.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![vec![1, 2], vec![], vec![3]];
+    /// let mut flattened = assert_that(&test_vec).flattening();
+    ///
+    /// flattened.has_length(3);
+    /// flattened.contains(&3);
+    /// flattened.element_at(2).is_equal_to(&3);
+    /// ```
+    fn flattening<U>(&mut self) -> Spec<'s, Flattened<'s, U>>
+    where
+        U: 's,
+        &'s T: IntoIterator<Item = &'s U>,
+    {
+        self.flat_extracting(|value| value)
+    }
+
+    /// Flattens the collections extracted from each element of the subject by the provided
+    /// function. The subject must implement `IntoIterator`.
+    ///
+    /// This will return a new `Spec` containing all the extracted elements, named like
+    /// `orders flattened`, on which any iterable assertion can be used.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// struct Order {
+    ///     lines: Vec<u32>,
+    /// }
+    ///
+    /// let orders = vec![Order { lines: vec![1, 2] }, Order { lines: vec![3] }];
+    /// assert_that(&orders)
+    ///     .flat_extracting(|order| &order.lines)
+    ///     .contains_all_of(&vec![&1, &3]);
+    /// ```
+    fn flat_extracting<C, U, F>(&mut self, extracting_function: F) -> Spec<'s, Flattened<'s, U>>
+    where
+        C: 's + ?Sized,
+        U: 's,
+        &'s C: IntoIterator<Item = &'s U>,
+        F: Fn(&'s T) -> &'s C,
+    {
+        let mut elements = vec![];
+        let mut positions = vec![];
+
+        for (outer_index, value) in self.subject.into_iter().enumerate() {
+            for (inner_index, element) in extracting_function(value).into_iter().enumerate() {
+                elements.push(element);
+                positions.push((outer_index, inner_index));
+            }
+        }
+
+        self.derive(
+            leak(Flattened {
+                elements,
+                positions,
+                root_name: self.subject_name.unwrap_or("iterable"),
+            }),
+            Some(self.nested_name("iterable", " flattened")),
+        )
+    }
+}
+
+impl<'s, T: 's> Spec<'s, Flattened<'s, T>>
+where
+    T: Debug,
+{
+    /// Asserts that the flattened subject has a first element, and returns a new `Spec` for it,
+    /// named after its position in the nested collection.
+    #[track_caller]
+    pub fn first(&mut self) -> Spec<'s, T> {
+        if self.subject.elements.is_empty() {
+            return PositionalIterAssertions::first(self);
+        }
+
+        self.derive_element(0)
+    }
+
+    /// Asserts that the flattened subject has a last element, and returns a new `Spec` for it,
+    /// named after its position in the nested collection.
+    #[track_caller]
+    pub fn last(&mut self) -> Spec<'s, T> {
+        match self.subject.elements.len() {
+            0 => PositionalIterAssertions::last(self),
+            length => self.derive_element(length - 1),
+        }
+    }
+
+    /// Asserts that the flattened subject has an element at the provided index, and returns a
+    /// new `Spec` for it, named after its position in the nested collection.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![vec![1, 2], vec![3, 4]];
+    /// assert_that(&test_vec)
+    ///     .flattening()
+    ///     .element_at(3)
+    ///     .is_equal_to(&4);
+    /// ```
+    #[track_caller]
+    pub fn element_at(&mut self, index: usize) -> Spec<'s, T> {
+        if index >= self.subject.elements.len() {
+            return PositionalIterAssertions::element_at(self, index);
+        }
+
+        self.derive_element(index)
+    }
+
+    /// Asserts that the flattened subject has a single element, and returns a new `Spec` for
+    /// it, named after its position in the nested collection.
+    #[track_caller]
+    pub fn single(&mut self) -> Spec<'s, T> {
+        if self.subject.elements.len() != 1 {
+            return PositionalIterAssertions::single(self);
+        }
+
+        self.derive_element(0)
+    }
+
+    /// Missing elements are left to the positional assertions above, which fail with their usual
+    /// messages: this only names the element found at `index`.
+    fn derive_element(&self, index: usize) -> Spec<'s, T> {
+        let (outer_index, inner_index) = self.subject.positions[index];
+        let name = format!(
+            "{}[{}][{}]",
+            self.subject.root_name, outer_index, inner_index
+        );
+
+        self.derive(self.subject.elements[index], Some(leak(name).as_str()))
+    }
+}
+
+impl<'a, 's, T> IntoIterator for &'a Flattened<'s, T> {
+    type Item = &'s T;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, &'s T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter().copied()
+    }
+}
+
+impl<T> Length for Flattened<'_, T> {
+    const NAME: &'static str = "flattened vec";
+
+    fn length(&self) -> usize {
+        self.elements.len()
+    }
+}
+
+impl<T: Debug> Debug for Flattened<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.elements).finish()
+    }
+}
+
+impl<I> ConsumingIteratorAssertions<I::Item> for IteratorSpec<'_, I>
+where
+    I: Iterator,
+    I::Item: Debug,
+{
+    /// Asserts that the next items of the subject iterator are the provided values, consuming
+    /// them one at a time and failing on the first mismatch. The subject is an `Iterator` owned
+    /// by the `IteratorSpec`, so that it does not need to implement `Clone`.
+    ///
+    /// `yields` returns &mut Self, making it possible to chain assertions on the remaining items.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that_iter(0..).yields([0, 1, 2]).yields([3]);
+    /// ```
+    fn yields<E>(&mut self, expected_values: E) -> &mut Self
     where
-        F: Fn(&'s T) -> bool,
+        I::Item: PartialEq,
+        E: IntoIterator,
+        E::Item: Borrow<I::Item>,
     {
-        let mut actual = Vec::new();
-        for x in self.subject {
-            if matcher(x) {
-                return;
-            } else {
-                actual.push(x);
+        let mut read_subject = vec![];
+        let mut read_expected = vec![];
+
+        for expected in expected_values {
+            match self.subject.next() {
+                Some(actual) => {
+                    if !actual.eq(expected.borrow()) {
+                        AssertionFailure::from_spec(self)
+                            .with_expected(format!(
+                                "Iterator item of <{:?}> (read <{:?}>)",
+                                expected.borrow(),
+                                borrow_all(&read_expected)
+                            ))
+                            .with_actual(format!(
+                                "Iterator item of <{:?}> (read <{:?}>)",
+                                actual, read_subject
+                            ))
+                            .fail();
+                    }
+
+                    read_subject.push(actual);
+                    read_expected.push(expected);
+                }
+                None => {
+                    AssertionFailure::from_spec(self)
+                        .with_expected(format!(
+                            "Iterator item of <{:?}> (read <{:?}>)",
+                            expected.borrow(),
+                            borrow_all(&read_expected)
+                        ))
+                        .with_actual(format!("Completed iterator (read <{:?}>)", read_subject))
+                        .fail();
+                }
             }
         }
-        AssertionFailure::from_spec(self).fail_with_message(format!(
-            "expectation failed for iterator with values <{:?}>",
-            actual
-        ));
+
+        self
     }
 
-    /// Maps the values of the subject before asserting that the mapped subject contains the
-    /// provided value. The subject must implement IntoIterator, and the type of the mapped
-    /// value must implement `PartialEq`.
-    ///
-    /// NOTE: The panic message will refer to the mapped values rather than the values present in
-    /// the original subject.
+    /// Asserts that the subject iterator yields exactly the expected number of items, consuming
+    /// them and failing as soon as one item too many is read.
     ///
     /// ```rust
-    /// #[derive(PartialEq, Debug)]
-    /// struct Simple {
-    ///     pub val: usize,
-    /// }
-    ///
-    /// //...
     /// # use speculoos::prelude::*;
-    /// assert_that(&vec![Simple { val: 1 }, Simple { val: 2 } ]).mapped_contains(|x| x.val, &2);
+    /// assert_that_iter((0..).step_by(2).take(3)).yields_exactly(3);
     /// ```
-    fn mapped_contains<F, M>(&mut self, mapping_function: F, expected_value: &'s M)
-    where
-        M: Debug + PartialEq,
-        F: Fn(&'s T) -> M,
-    {
-        let subject = self.subject;
+    fn yields_exactly(&mut self, expected: usize) {
+        let mut read_subject = vec![];
 
-        let mapped_vec: Vec<M> = subject.into_iter().map(mapping_function).collect();
-        if mapped_vec.contains(expected_value) {
-            return;
+        while read_subject.len() <= expected {
+            match self.subject.next() {
+                Some(actual) => read_subject.push(actual),
+                None => break,
+            }
         }
 
-        panic_unmatched(self, expected_value, mapped_vec, true);
+        if read_subject.len() > expected {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("iterator yielding <{}> items", expected))
+                .with_actual(format!(
+                    "at least <{}> items (read <{:?}>)",
+                    read_subject.len(),
+                    read_subject
+                ))
+                .fail();
+        } else if read_subject.len() < expected {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("iterator yielding <{}> items", expected))
+                .with_actual(format!(
+                    "<{}> items (read <{:?}>)",
+                    read_subject.len(),
+                    read_subject
+                ))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject iterator does not yield any more items.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that_iter(vec![1, 2].into_iter())
+    ///     .yields([1, 2])
+    ///     .is_exhausted();
+    /// ```
+    fn is_exhausted(&mut self) {
+        if let Some(actual) = self.subject.next() {
+            AssertionFailure::from_spec(self)
+                .with_expected("Completed iterator".to_string())
+                .with_actual(format!("Iterator item of <{:?}>", actual))
+                .fail();
+        }
     }
 }
 
-fn check_iterator_contains<'s, T, V, I, E: Borrow<V>>(
+fn check_iterator_contains<'s, T: ?Sized, V, I, E: Borrow<V>>(
     spec: &mut Spec<T>,
     actual_iter: I,
     expected_value: E,
@@ -279,7 +1047,34 @@ fn check_iterator_contains<'s, T, V, I, E: Borrow<V>>(
     }
 }
 
-fn check_iterator_contains_all_of<T, V, I, E>(
+fn check_iterator_contains_all_of<T: ?Sized, V, I, E>(
+    spec: &mut Spec<T>,
+    actual_iter: I,
+    expected_values_iter: E,
+) where
+    V: PartialEq + Debug,
+    I: Iterator<Item = V>,
+    E: Iterator<Item = V>,
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+
+    let (mut matched_values, mut unmatched_values, _) =
+        match_expected_values(&actual_values, expected_values_iter, V::eq);
+
+    if !unmatched_values.is_empty() {
+        let mut expected_values: Vec<V> = vec![];
+
+        expected_values.append(&mut matched_values);
+        expected_values.append(&mut unmatched_values);
+
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("iterator to contain items <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .fail();
+    }
+}
+
+fn check_iterator_has_same_elements<T: ?Sized, V, I, E>(
     spec: &mut Spec<T>,
     actual_iter: I,
     expected_values_iter: E,
@@ -289,7 +1084,91 @@ fn check_iterator_contains_all_of<T, V, I, E>(
     E: Iterator<Item = V>,
 {
     let actual_values: Vec<V> = actual_iter.collect();
+    let expected_values: Vec<V> = expected_values_iter.collect();
+
+    let actual_refs: Vec<&V> = actual_values.iter().collect();
+
+    let (_, missing_values, matched_indexes) =
+        match_expected_values(&actual_refs, expected_values.iter(), |a, b| a.eq(b));
+    let extra_values: Vec<&V> = actual_values
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !matched_indexes.contains(index))
+        .map(|(_, value)| value)
+        .collect();
+
+    if missing_values.is_empty() && extra_values.is_empty() {
+        return;
+    }
+
+    let mut differing_values: Vec<&V> = vec![];
+
+    for value in missing_values.into_iter().chain(extra_values) {
+        if !differing_values.contains(&value) {
+            differing_values.push(value);
+        }
+    }
+
+    let differing_counts: Vec<(&V, usize, usize)> = differing_values
+        .into_iter()
+        .map(|value| {
+            (
+                value,
+                expected_values
+                    .iter()
+                    .filter(|other| *other == value)
+                    .count(),
+                actual_values.iter().filter(|other| *other == value).count(),
+            )
+        })
+        .collect();
+
+    fail_with_differing_counts(spec, &actual_values, &expected_values, &differing_counts);
+}
+
+/// Fails a multiset comparison with a table of the values whose expected and actual counts
+/// differ.
+#[track_caller]
+fn fail_with_differing_counts<T: ?Sized, V: Debug, D: Debug>(
+    spec: &mut Spec<T>,
+    actual_values: &[V],
+    expected_values: &[V],
+    differing_counts: &[(D, usize, usize)],
+) {
+    let count_table: String = differing_counts
+        .iter()
+        .map(|(value, expected_count, actual_count)| {
+            format!(
+                "\n\t\t<{:?}>: expected count {}, actual count {}",
+                value, expected_count, actual_count
+            )
+        })
+        .collect();
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(format!(
+            "iterator to have same elements as <{:?}>",
+            expected_values
+        ))
+        .with_actual(format!(
+            "<{:?}>, with differing counts:{}",
+            actual_values, count_table
+        ))
+        .fail();
+}
 
+/// Pairs each expected value with a distinct actual value equal to it according to the
+/// comparator. Returns the matched and unmatched expected values, and the indexes of the actual
+/// values which were matched.
+pub(crate) fn match_expected_values<V, E, C>(
+    actual_values: &[V],
+    expected_values_iter: E,
+    comparator: C,
+) -> (Vec<V>, Vec<V>, Vec<usize>)
+where
+    E: Iterator<Item = V>,
+    C: Fn(&V, &V) -> bool,
+{
     let mut matched_indexes = vec![];
     let mut matched_indexes_holder = vec![];
 
@@ -304,7 +1183,7 @@ fn check_iterator_contains_all_of<T, V, I, E>(
             .enumerate()
             .filter(|&(i, _)| !matched_indexes.contains(&i))
         {
-            if expected.eq(actual) {
+            if comparator(&expected, actual) {
                 matched_indexes_holder.push(index);
                 matched_values.push(expected);
                 continue 'outer;
@@ -314,20 +1193,12 @@ fn check_iterator_contains_all_of<T, V, I, E>(
         unmatched_values.push(expected);
     }
 
-    if !unmatched_values.is_empty() {
-        let mut expected_values: Vec<V> = vec![];
-
-        expected_values.append(&mut matched_values);
-        expected_values.append(&mut unmatched_values);
+    matched_indexes.append(&mut matched_indexes_holder);
 
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("iterator to contain items <{:?}>", expected_values))
-            .with_actual(format!("<{:?}>", actual_values))
-            .fail();
-    }
+    (matched_values, unmatched_values, matched_indexes)
 }
 
-fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_iter: E)
+fn compare_iterators<T: ?Sized, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_iter: E)
 where
     V: PartialEq + Debug,
     I: Iterator<Item = V>,
@@ -389,7 +1260,21 @@ where
     }
 }
 
-fn panic_unmatched<T, E: Debug, A: Debug>(
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else {
+        "<non string panic payload>".to_string()
+    }
+}
+
+pub(crate) fn borrow_all<T: ?Sized, B: Borrow<T>>(values: &[B]) -> Vec<&T> {
+    values.iter().map(|value| value.borrow()).collect()
+}
+
+fn panic_unmatched<T: ?Sized, E: Debug, A: Debug>(
     spec: &mut Spec<T>,
     expected: E,
     actual: A,
@@ -414,7 +1299,7 @@ mod tests {
     #![allow(clippy::needless_borrows_for_generic_args)]
 
     use super::super::prelude::*;
-    use std::collections::LinkedList;
+    use std::collections::{LinkedList, VecDeque};
 
     #[test]
 
@@ -635,6 +1520,391 @@ mod tests {
         assert_that(&test_vec).mapped_contains(|val| val.value, &1);
     }
 
+    #[test]
+    #[allow(clippy::useless_vec)]
+    fn should_not_panic_if_slice_contains_value() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec[..]).contains(&2);
+    }
+
+    #[test]
+    fn should_return_positional_elements_of_iterable() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).first().is_equal_to(&1);
+        assert_that(&test_vec).last().is_equal_to(&3);
+        assert_that(&test_vec).element_at(1).is_equal_to(&2);
+        assert_that(&[4]).single().is_equal_to(&4);
+    }
+
+    #[test]
+    #[allow(clippy::useless_vec)]
+    fn should_return_positional_elements_of_slice_and_deque() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec[1..]).first().is_equal_to(&2);
+
+        let test_deque: VecDeque<u8> = VecDeque::from(vec![1, 2, 3]);
+        assert_that(&test_deque).last().is_equal_to(&3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [iterable[2]]\n\texpected: <4>\n\t but was: <3>")]
+    fn should_name_element_after_its_index() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).element_at(2).is_equal_to(&4);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [iterable[0]]\n\texpected: <2>\n\t but was: <1>")]
+    fn should_name_element_of_any_iterable_after_its_index() {
+        let test_set: std::collections::BTreeSet<u8> = [1].into_iter().collect();
+        assert_that(&test_set).single().is_equal_to(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [orders[2]]\n\texpected: <4>\n\t but was: <3>")]
+    fn should_name_last_element_after_subject_name() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec)
+            .named("orders")
+            .last()
+            .is_equal_to(&4);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have a first element\n\t but was: <[]>")]
+    fn should_panic_if_iterable_has_no_first_element() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).first();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have a last element\n\t but was: <[]>")]
+    fn should_panic_if_iterable_has_no_last_element() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).last();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have an element at index <3>\
+                   \n\t but was: <[1, 2, 3]>")]
+    fn should_panic_if_iterable_has_no_element_at_index() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).element_at(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have a single element\
+                   \n\t but was: <[1, 2]>")]
+    fn should_panic_if_iterable_has_more_than_one_element() {
+        let test_vec = vec![1, 2];
+        assert_that(&test_vec).single();
+    }
+
+    #[test]
+    fn should_not_panic_if_iterator_yields_expected_values() {
+        assert_that_iter(vec![1, 2, 3].into_iter())
+            .yields([1, 2])
+            .yields(vec![&3])
+            .is_exhausted();
+    }
+
+    #[test]
+    fn should_consume_infinite_iterator_lazily() {
+        assert_that_iter((1..).map(|value| value * 2)).yields([2, 4, 6]);
+        assert_that_iter((1..).take(5)).yields_exactly(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Iterator item of <4> (read <[1, 2]>)\
+                   \n\t but was: Iterator item of <3> (read <[1, 2]>)")]
+    fn should_panic_if_iterator_does_not_yield_expected_values() {
+        assert_that_iter(1..).yields([1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Iterator item of <3> (read <[1, 2]>)\
+                   \n\t but was: Completed iterator (read <[1, 2]>)")]
+    fn should_panic_if_iterator_completes_before_expected_values() {
+        assert_that_iter(vec![1, 2].into_iter()).yields([1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator yielding <2> items\
+                   \n\t but was: at least <3> items (read <[0, 1, 2]>)")]
+    fn should_panic_early_if_iterator_yields_too_many_items() {
+        assert_that_iter(0..).yields_exactly(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator yielding <3> items\
+                   \n\t but was: <2> items (read <[0, 1]>)")]
+    fn should_panic_if_iterator_yields_too_few_items() {
+        assert_that_iter(0..2).yields_exactly(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Completed iterator\n\t but was: Iterator item of <3>")]
+    fn should_panic_if_iterator_is_not_exhausted() {
+        assert_that_iter(vec![1, 2, 3].into_iter())
+            .yields([1, 2])
+            .is_exhausted();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [ids]\n\texpected: Completed iterator\
+                   \n\t but was: Iterator item of <1>")]
+    fn should_name_iterator_subject() {
+        assert_that_iter(0..2)
+            .named("ids")
+            .yields([0])
+            .is_exhausted();
+    }
+
+    #[test]
+    #[should_panic(expected = "draining:\n\texpected: Completed iterator")]
+    fn should_describe_iterator_assertion() {
+        asserting("draining").that_iter(0..1).is_exhausted();
+    }
+
+    #[test]
+    fn should_group_elements_by_key() {
+        let test_vec = vec![
+            TestStruct { value: 1 },
+            TestStruct { value: 2 },
+            TestStruct { value: 3 },
+        ];
+
+        let mut groups = assert_that(&test_vec).grouped_by(|item| item.value % 2);
+
+        groups.has_length(2);
+        groups
+            .contains_key(1)
+            .equals_iterator(&[&test_vec[0], &test_vec[2]].iter());
+        groups.contains_entry(0, vec![&test_vec[1]]);
+        groups.does_not_contain_key(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [orders]\
+                   \n\texpected: hashmap containing key <0> with value <[TestStruct { value: 1 }]>\
+                   \n\t but was: key <0> with value <[TestStruct { value: 2 }]> instead")]
+    fn should_panic_with_key_if_group_does_not_match() {
+        let test_vec = vec![TestStruct { value: 1 }, TestStruct { value: 2 }];
+
+        assert_that(&test_vec)
+            .named("orders")
+            .grouped_by(|item| item.value % 2)
+            .contains_entry(0, vec![&test_vec[0]]);
+    }
+
+    #[test]
+    fn should_partition_elements_by_predicate() {
+        let test_vec = vec![1, 2, 3, 4, 5];
+        let (mut even, mut odd) = assert_that(&test_vec).partitioned_by(|value| value % 2 == 0);
+
+        even.is_equal_to(vec![&2, &4]);
+        odd.is_equal_to(vec![&1, &3, &5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [iterable not matching predicate]\
+                   \n\texpected: vec to have length <2>\n\t but was: <3>")]
+    fn should_name_partition_halves_after_predicate() {
+        let test_vec = vec![1, 2, 3, 4, 5];
+        let (_, mut odd) = assert_that(&test_vec).partitioned_by(|value| value % 2 == 0);
+
+        odd.has_length(2);
+    }
+
+    #[test]
+    fn should_not_panic_if_all_pairs_satisfy_predicate() {
+        let test_vec = vec![TestStruct { value: 1 }, TestStruct { value: 2 }];
+        let expected = [1, 2];
+
+        assert_that(&test_vec)
+            .zip_satisfies(&expected, |actual, expected| actual.value == *expected);
+        assert_that(&test_vec).zip_satisfies(&expected, |actual, expected| {
+            assert_that(&actual.value).is_equal_to(expected);
+            true
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: pair at index <1> to satisfy predicate\
+                   \n\t but was: <TestStruct { value: 2 }> paired with <3>")]
+    fn should_panic_if_pair_does_not_satisfy_predicate() {
+        let test_vec = vec![TestStruct { value: 1 }, TestStruct { value: 2 }];
+
+        assert_that(&test_vec).zip_satisfies(&[1, 3], |actual, expected| actual.value == *expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: pair at index <0> to satisfy predicate\
+                   \n\t but was: <TestStruct { value: 1 }> paired with <4>, failing with:\
+                   \n\tfor subject [value]\n\texpected: <4>\n\t but was: <1>")]
+    fn should_panic_if_pair_fails_nested_assertion() {
+        let test_vec = vec![TestStruct { value: 1 }];
+
+        assert_that(&test_vec).zip_satisfies(&[4], |actual, expected| {
+            assert_that(&actual.value)
+                .named("value")
+                .is_equal_to(expected);
+            true
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Iterator item of <3> (read <[1, 2]>)\
+                   \n\t but was: Completed iterator (read <[1, 2]>)")]
+    fn should_panic_if_zipped_collections_do_not_have_same_length() {
+        let test_vec = vec![1, 2];
+
+        assert_that(&test_vec).zip_satisfies(&[1, 2, 3], |actual, expected| actual == expected);
+    }
+
+    #[test]
+    fn should_not_panic_if_iterable_has_same_elements_in_any_order() {
+        let test_vec = vec![1, 2, 1, 3];
+        let expected: VecDeque<u8> = [3, 1, 2, 1].into_iter().collect();
+
+        assert_that(&test_vec).has_same_elements_as(&expected);
+        assert_that(&test_vec).has_same_elements_as(&[1, 1, 2, 3]);
+        assert_that(&test_vec.iter()).has_same_elements_as(&expected);
+    }
+
+    #[test]
+    fn should_not_panic_if_partial_eq_elements_are_the_same() {
+        let test_vec = vec![0.5, 1.5, 0.5];
+        assert_that(&test_vec).has_same_elements_as(&[0.5, 0.5, 1.5]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: iterator to have same elements as <[1, 1, 2, 4]>\
+                   \n\t but was: <[1, 2, 2, 3]>, with differing counts:\
+                   \n\t\t<1>: expected count 2, actual count 1\
+                   \n\t\t<4>: expected count 1, actual count 0\
+                   \n\t\t<2>: expected count 1, actual count 2\
+                   \n\t\t<3>: expected count 0, actual count 1"
+    )]
+    fn should_panic_with_count_table_if_iterable_does_not_have_same_elements() {
+        let test_vec = vec![1, 2, 2, 3];
+        assert_that(&test_vec).has_same_elements_as(&[1, 1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have same elements as <[1]>\
+                   \n\t but was: <[1, 1]>, with differing counts:\
+                   \n\t\t<1>: expected count 1, actual count 2")]
+    fn should_panic_if_iterator_has_extra_occurrences() {
+        let test_vec = [1, 1];
+        assert_that(&test_vec.iter()).has_same_elements_as(&[1]);
+    }
+
+    #[test]
+    fn should_not_panic_if_hashed_elements_are_the_same() {
+        let test_vec = vec!["b", "a", "b"];
+        let expected: VecDeque<&str> = ["a", "b", "b"].into_iter().collect();
+
+        assert_that(&test_vec).has_same_hashed_elements_as(&expected);
+        assert_that(&Vec::<u8>::new()).has_same_hashed_elements_as(&[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: iterator to have same elements as <[1, 1, 2, 4]>\
+                   \n\t but was: <[1, 2, 2, 3]>, with differing counts:\
+                   \n\t\t<1>: expected count 2, actual count 1\
+                   \n\t\t<2>: expected count 1, actual count 2\
+                   \n\t\t<4>: expected count 1, actual count 0\
+                   \n\t\t<3>: expected count 0, actual count 1"
+    )]
+    fn should_panic_with_count_table_if_hashed_elements_differ() {
+        let test_vec = vec![1, 2, 2, 3];
+        assert_that(&test_vec).has_same_hashed_elements_as(&[1, 1, 2, 4]);
+    }
+
+    #[test]
+    fn should_allow_iterable_assertions_on_flattened_subject() {
+        let test_vec = vec![vec![1, 2], vec![], vec![3, 4]];
+        let expected_values = vec![&4, &1];
+        let mut flattened = assert_that(&test_vec).flattening();
+
+        flattened.has_length(4);
+        flattened.contains(&3);
+        flattened.contains_all_of(&expected_values);
+        flattened.has_same_elements_as(&[4, 3, 2, 1]);
+        flattened.first().is_equal_to(&1);
+        flattened.last().is_equal_to(&4);
+    }
+
+    #[test]
+    fn should_flatten_extracted_collections() {
+        struct Order {
+            lines: Vec<TestStruct>,
+        }
+
+        let orders = vec![
+            Order {
+                lines: vec![TestStruct { value: 1 }],
+            },
+            Order {
+                lines: vec![TestStruct { value: 2 }, TestStruct { value: 3 }],
+            },
+        ];
+
+        assert_that(&orders)
+            .flat_extracting(|order| &order.lines)
+            .mapped_contains(|line| line.value, &3);
+        assert_that(&orders)
+            .flat_extracting(|order| order.lines.as_slice())
+            .has_length(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [orders[2][1]]\n\texpected: <5>\n\t but was: <4>")]
+    fn should_name_flattened_element_after_nested_position() {
+        let test_vec = vec![vec![1, 2], vec![], vec![3, 4]];
+        assert_that(&test_vec)
+            .named("orders")
+            .flattening()
+            .element_at(3)
+            .is_equal_to(&5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [iterable[1][0]]\n\texpected: <1>\n\t but was: <2>")]
+    fn should_name_single_flattened_element_after_nested_position() {
+        let test_vec: Vec<Vec<u8>> = vec![vec![], vec![2]];
+        assert_that(&test_vec).flattening().single().is_equal_to(&1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: flattened vec to have length <2>\n\t but was: <3>")]
+    fn should_panic_if_flattened_length_does_not_match_expected() {
+        let test_vec = vec![vec![1, 2], vec![3]];
+        assert_that(&test_vec).flattening().has_length(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [orders flattened]\
+                   \n\texpected: iterator to contain <5>")]
+    fn should_name_flattened_subject_in_failures() {
+        let test_vec = vec![vec![1, 2], vec![3]];
+        assert_that(&test_vec)
+            .named("orders")
+            .flattening()
+            .contains(&5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [iterable flattened]\
+                   \n\texpected: iterator to have an element at index <3>")]
+    fn should_panic_if_flattened_element_is_missing() {
+        let test_vec = vec![vec![1, 2], vec![3]];
+        assert_that(&test_vec).flattening().element_at(3);
+    }
+
     #[derive(Debug, PartialEq)]
     struct TestStruct {
         pub value: u8,
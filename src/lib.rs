@@ -1,4 +1,5 @@
 #![allow(clippy::wrong_self_convention)]
+#![allow(clippy::test_attr_in_doctest)]
 
 //! Fluent test assertions in Rust
 //!
@@ -141,22 +142,36 @@ use std::cmp::PartialEq;
 use std::fmt::Debug;
 
 use colours::{TERM_BOLD, TERM_RED, TERM_RESET};
+use iter::IteratorSpec;
 
 pub mod boolean;
+pub mod comparator;
+mod diff;
 pub mod hashmap;
 pub mod hashset;
 pub mod iter;
 pub mod numeric;
 pub mod option;
 pub mod path;
+mod pattern;
 pub mod prelude;
 pub mod result;
+pub mod sized;
 pub mod string;
 pub mod vec;
 
 #[cfg(feature = "json")]
 pub mod json;
 
+#[cfg(feature = "serde")]
+pub mod recursive;
+
+#[cfg(feature = "regex")]
+pub mod regex;
+
+#[cfg(feature = "unicode")]
+pub mod unicode;
+
 // Disable colours during tests, otherwise trying to assert on the panic message becomes
 // significantly more annoying.
 #[cfg(not(test))]
@@ -218,6 +233,7 @@ pub struct AssertionFailure<'r, T: 'r> {
     spec: &'r T,
     expected: Option<String>,
     actual: Option<String>,
+    details: Option<String>,
 }
 
 /// A description for an assertion.
@@ -234,7 +250,7 @@ pub struct SpecDescription<'r> {
 /// This is created by either the `assert_that` function, or by calling `that` on a
 /// `SpecDescription`.
 #[derive(Debug)]
-pub struct Spec<'s, S: 's> {
+pub struct Spec<'s, S: 's + ?Sized> {
     pub subject: &'s S,
     pub subject_name: Option<&'s str>,
     pub location: Option<String>,
@@ -244,7 +260,7 @@ pub struct Spec<'s, S: 's> {
 /// Wraps a subject in a `Spec` to provide assertions against it.
 ///
 /// The subject must be a reference.
-pub fn assert_that<S>(subject: &S) -> Spec<S> {
+pub fn assert_that<S: ?Sized>(subject: &S) -> Spec<'_, S> {
     Spec {
         subject,
         subject_name: None,
@@ -253,8 +269,20 @@ pub fn assert_that<S>(subject: &S) -> Spec<S> {
     }
 }
 
+/// Wraps a single-pass iterator in an `IteratorSpec` to provide assertions consuming it.
+///
+/// The iterator is taken by value, so it does not need to implement `Clone`.
+pub fn assert_that_iter<'s, I: Iterator>(subject: I) -> IteratorSpec<'s, I> {
+    IteratorSpec {
+        subject,
+        subject_name: None,
+        location: None,
+        description: None,
+    }
+}
+
 /// Describes an assertion.
-pub fn asserting(description: &str) -> SpecDescription {
+pub fn asserting(description: &str) -> SpecDescription<'_> {
     SpecDescription {
         value: description,
         location: None,
@@ -268,7 +296,7 @@ impl<'r> SpecDescription<'r> {
     }
 
     /// Creates a new assertion, passing through its description.
-    pub fn that<S>(self, subject: &'r S) -> Spec<'r, S> {
+    pub fn that<S: ?Sized>(self, subject: &'r S) -> Spec<'r, S> {
         Spec {
             subject,
             subject_name: None,
@@ -276,9 +304,19 @@ impl<'r> SpecDescription<'r> {
             description: Some(self.value),
         }
     }
+
+    /// Creates a new assertion over a single-pass iterator, passing through its description.
+    pub fn that_iter<I: Iterator>(self, subject: I) -> IteratorSpec<'r, I> {
+        IteratorSpec {
+            subject,
+            subject_name: None,
+            location: self.location,
+            description: Some(self.value),
+        }
+    }
 }
 
-impl<'r, T> DescriptiveSpec<'r> for Spec<'r, T> {
+impl<'r, T: ?Sized> DescriptiveSpec<'r> for Spec<'r, T> {
     fn subject_name(&self) -> Option<&'r str> {
         self.subject_name
     }
@@ -299,6 +337,7 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
             spec,
             expected: None,
             actual: None,
+            details: None,
         }
     }
 
@@ -316,8 +355,17 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
         self
     }
 
+    /// Builder method to add details (such as a diff) after the actual value in the panic
+    /// message. Each line of the details should start with `\n\t`.
+    pub fn with_details(&mut self, details: String) -> &mut Self {
+        self.details = Some(details);
+
+        self
+    }
+
     /// Builds the failure message with a description (if present), the expected value,
-    /// and the actual value and then calls `panic` with the created message.
+    /// the actual value and the details (if present) and then calls `panic` with the created
+    /// message.
     #[track_caller]
     pub fn fail(&mut self) {
         assert!(
@@ -330,12 +378,13 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
         let description = self.maybe_build_description();
 
         panic!(
-            "{}{}\n\t{}expected: {}\n\t but was: {}{}\n{}",
+            "{}{}\n\t{}expected: {}\n\t but was: {}{}{}\n{}",
             description,
             subject_name,
             TERM_RED,
             self.expected.clone().unwrap(),
             self.actual.clone().unwrap(),
+            self.details.clone().unwrap_or_default(),
             TERM_RESET,
             location
         )
@@ -377,7 +426,7 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
     }
 }
 
-impl<'s, S> Spec<'s, S> {
+impl<'s, S: ?Sized> Spec<'s, S> {
     /// Provides the actual location of the assertion.
     ///
     /// Usually you would not call this directly, but use the macro forms of `assert_that` and
@@ -396,11 +445,44 @@ impl<'s, S> Spec<'s, S> {
 
         self
     }
+
+    /// Creates a new `Spec` over a value reached from the subject, keeping the location and
+    /// description of this one.
+    pub(crate) fn derive<T: ?Sized>(
+        &self,
+        subject: &'s T,
+        subject_name: Option<&'s str>,
+    ) -> Spec<'s, T> {
+        Spec {
+            subject,
+            subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        }
+    }
+
+    /// Builds the name of a value reached from the subject, such as `iterable[3]`. The subject name
+    /// is used as the root if there is one, `default_root` otherwise. The name is leaked.
+    pub(crate) fn nested_name(&self, default_root: &str, accessor: &str) -> &'s str {
+        let root = self.subject_name.unwrap_or(default_root);
+        leak(format!("{}{}", root, accessor)).as_str()
+    }
+}
+
+/// Moves a value derived during an assertion to the heap and hands out a reference living as
+/// long as the `Spec` it is attached to.
+///
+/// Specs only ever borrow their subject, so navigating to a computed value (a name, a group of
+/// elements, a parsed value...) requires it to outlive the assertion chain. The memory is never
+/// freed, and is leaked again by every navigation whether its assertions pass or not: navigating
+/// inside a loop or a property test leaks once per iteration.
+pub(crate) fn leak<'s, T: 's>(value: T) -> &'s T {
+    Box::leak(Box::new(value))
 }
 
 impl<S> Spec<'_, S>
 where
-    S: Debug + PartialEq,
+    S: Debug + PartialEq + ?Sized,
 {
     /// Asserts that the actual value and the expected value are equal. The value type must
     /// implement `PartialEq`.
@@ -444,6 +526,66 @@ where
                 .fail();
         }
     }
+
+    /// Asserts that the actual value is equal to one of the expected values. The value type must
+    /// implement `PartialEq`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&204).is_one_of([200, 201, 204]);
+    /// ```
+    #[track_caller]
+    pub fn is_one_of<E>(&mut self, expected_values: E)
+    where
+        E: IntoIterator,
+        E::Item: Borrow<S>,
+    {
+        let subject = self.subject;
+        let expected_values: Vec<E::Item> = expected_values.into_iter().collect();
+
+        if !expected_values
+            .iter()
+            .any(|value| subject.eq(value.borrow()))
+        {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "one of <{:?}>",
+                    iter::borrow_all::<S, _>(&expected_values)
+                ))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that the actual value is equal to none of the provided values. The value type
+    /// must implement `PartialEq`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&404).is_not_one_of([200, 201, 204]);
+    /// ```
+    #[track_caller]
+    pub fn is_not_one_of<E>(&mut self, expected_values: E)
+    where
+        E: IntoIterator,
+        E::Item: Borrow<S>,
+    {
+        let subject = self.subject;
+        let expected_values: Vec<E::Item> = expected_values.into_iter().collect();
+
+        if expected_values
+            .iter()
+            .any(|value| subject.eq(value.borrow()))
+        {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "none of <{:?}>",
+                    iter::borrow_all::<S, _>(&expected_values)
+                ))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+    }
 }
 
 impl<'s, S> Spec<'s, S>
@@ -591,6 +733,38 @@ mod tests {
         assert_that(&1).is_not_equal_to(&1);
     }
 
+    #[test]
+    fn should_not_panic_if_value_is_one_of_expected() {
+        #[derive(Debug, PartialEq)]
+        enum State {
+            Pending,
+            Done,
+        }
+
+        assert_that(&2).is_one_of([1, 2, 3]);
+        assert_that(&2).is_one_of(&vec![2]);
+        assert_that(&State::Done).is_one_of([State::Pending, State::Done]);
+        assert_that(&"hello").is_one_of(["hi", "hello"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: one of <[200, 201, 204]>\n\t but was: <404>")]
+    fn should_panic_if_value_is_not_one_of_expected() {
+        assert_that(&404).is_one_of([200, 201, 204]);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_not_one_of_unexpected() {
+        assert_that(&4).is_not_one_of([1, 2, 3]);
+        assert_that(&4).is_not_one_of(Vec::<i32>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: none of <[1, 2, 3]>\n\t but was: <2>")]
+    fn should_panic_if_value_is_one_of_unexpected() {
+        assert_that(&2).is_not_one_of([1, 2, 3]);
+    }
+
     #[test]
     fn should_not_panic_if_value_matches() {
         let value = "Hello";
@@ -142,6 +142,160 @@ impl<T: Float + Debug> FloatAssertions<T> for Spec<'_, T> {
     }
 }
 
+#[cfg(feature = "num")]
+pub trait FloatIterAssertions<T: Float> {
+    #[track_caller]
+    fn is_close_to_each<E: AsRef<[T]>, O: Borrow<T>>(&mut self, expected: E, tolerance: O);
+    #[track_caller]
+    fn is_relatively_close_to_each<E: AsRef<[T]>, O: Borrow<T>>(
+        &mut self,
+        expected: E,
+        relative_tolerance: O,
+    );
+}
+
+#[cfg(feature = "num")]
+impl<'s, T: 's, I: ?Sized> FloatIterAssertions<T> for Spec<'s, I>
+where
+    T: Float + Debug,
+    &'s I: IntoIterator<Item = &'s T>,
+{
+    /// Asserts that each element of the subject is close to the element of the expected slice at
+    /// the same index, by the specified tolerance. The contained type must implement `Float`
+    /// and `Debug`.
+    ///
+    /// A NaN element, on either side, is never close to anything, and a NaN tolerance fails the
+    /// assertion. An infinite element is only close to the same infinity.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&vec![1.0f64, 2.0]).is_close_to_each([1.001, 1.999], 0.01);
+    /// ```
+    fn is_close_to_each<E: AsRef<[T]>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) {
+        let borrowed_tolerance = tolerance.borrow().abs();
+
+        check_close_to_each(
+            self,
+            expected.as_ref(),
+            "tolerance",
+            borrowed_tolerance,
+            |_, difference| difference <= borrowed_tolerance,
+        );
+    }
+
+    /// Asserts that each element of the subject is close to the element of the expected slice at
+    /// the same index, the tolerance being relative to the magnitude of the expected element.
+    /// The contained type must implement `Float` and `Debug`.
+    ///
+    /// An expected element of zero therefore only accepts an actual element of exactly zero; use
+    /// `is_close_to_each` for values expected around zero. NaN elements and tolerances fail the
+    /// assertion, as with `is_close_to_each`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&vec![100.0f64, 0.01]).is_relatively_close_to_each([101.0, 0.0101], 0.02);
+    /// ```
+    fn is_relatively_close_to_each<E: AsRef<[T]>, O: Borrow<T>>(
+        &mut self,
+        expected: E,
+        relative_tolerance: O,
+    ) {
+        let borrowed_tolerance = relative_tolerance.borrow().abs();
+
+        check_close_to_each(
+            self,
+            expected.as_ref(),
+            "relative tolerance",
+            borrowed_tolerance,
+            |expected, difference| difference <= borrowed_tolerance * expected.abs(),
+        );
+    }
+}
+
+#[cfg(feature = "num")]
+fn check_close_to_each<'s, T, I, F>(
+    spec: &mut Spec<'s, I>,
+    expected: &[T],
+    tolerance_kind: &str,
+    tolerance: T,
+    is_close: F,
+) where
+    T: 's + Float + Debug,
+    I: ?Sized,
+    &'s I: IntoIterator<Item = &'s T>,
+    F: Fn(T, T) -> bool,
+{
+    let actual: Vec<T> = spec.subject.into_iter().copied().collect();
+    let expected_message = format!(
+        "floats close to <{:?}> ({} of <{:?}>)",
+        expected, tolerance_kind, tolerance
+    );
+
+    if tolerance.is_nan() {
+        AssertionFailure::from_spec(spec)
+            .with_expected(expected_message)
+            .with_actual(format!("<{:?}>, with a NaN {}", actual, tolerance_kind))
+            .fail();
+
+        unreachable!();
+    }
+
+    if actual.len() != expected.len() {
+        AssertionFailure::from_spec(spec)
+            .with_expected(expected_message)
+            .with_actual(format!(
+                "<{:?}> of length <{}> instead of <{}>",
+                actual,
+                actual.len(),
+                expected.len()
+            ))
+            .fail();
+
+        unreachable!();
+    }
+
+    let mut max_error = T::zero();
+    let mut divergences = vec![];
+
+    for (index, (&actual_value, &expected_value)) in actual.iter().zip(expected).enumerate() {
+        // Equal infinities are a match, although their difference is NaN.
+        if actual_value == expected_value {
+            continue;
+        }
+
+        let difference = (actual_value - expected_value).abs();
+
+        // `Float::max` ignores NaN, which would hide it from the reported error.
+        max_error = if difference.is_nan() || max_error.is_nan() {
+            T::nan()
+        } else {
+            max_error.max(difference)
+        };
+
+        if !actual_value.is_finite()
+            || expected_value.is_nan()
+            || !is_close(expected_value, difference)
+        {
+            divergences.push(format!(
+                "index <{}> (<{:?}> instead of <{:?}>)",
+                index, actual_value, expected_value
+            ));
+        }
+    }
+
+    if !divergences.is_empty() {
+        AssertionFailure::from_spec(spec)
+            .with_expected(expected_message)
+            .with_actual(format!(
+                "<{:?}> diverging at {}, max absolute error of <{:?}>",
+                actual,
+                divergences.join(", "),
+                max_error
+            ))
+            .fail();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::needless_borrows_for_generic_args)]
@@ -269,4 +423,127 @@ mod tests {
     fn should_panic_if_float_is_negative_infinity() {
         assert_that(&Float::neg_infinity()).is_close_to(1.0f64, 0.01f64);
     }
+
+    #[test]
+    #[cfg(feature = "num")]
+    fn should_not_panic_if_floats_are_close_to_each_expected() {
+        let test_vec = vec![1.0f64, 2.0, 3.0];
+        assert_that(&test_vec).is_close_to_each([1.001, 1.999, 3.0], 0.01);
+        assert_that(&test_vec).is_close_to_each(&test_vec, 0.0);
+        assert_that(&test_vec[..2]).is_close_to_each(vec![1.0, 2.0], 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[1.0, 2.0, 3.0, 4.0]> (tolerance of <0.01>)\
+                   \n\t but was: <[1.0, 2.5, 3.0, 3.0]> diverging at index <1> (<2.5> instead of <2.0>), \
+                   index <3> (<3.0> instead of <4.0>), max absolute error of <1.0>"
+    )]
+    fn should_panic_if_floats_are_not_close_to_each_expected() {
+        let test_vec = vec![1.0f64, 2.5, 3.0, 3.0];
+        assert_that(&test_vec).is_close_to_each([1.0, 2.0, 3.0, 4.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[1.0, 2.0]> (tolerance of <0.01>)\
+                   \n\t but was: <[1.0, NaN]> diverging at index <1> (<NaN> instead of <2.0>), \
+                   max absolute error of <NaN>"
+    )]
+    fn should_panic_if_floats_contain_nan() {
+        let test_vec = vec![1.0f64, Float::nan()];
+        assert_that(&test_vec).is_close_to_each([1.0, 2.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    fn should_not_panic_if_floats_are_the_same_infinities() {
+        let test_vec = vec![f64::INFINITY, f64::NEG_INFINITY, 1.0];
+        assert_that(&test_vec).is_close_to_each(&test_vec, 0.01);
+        assert_that(&test_vec).is_relatively_close_to_each(&test_vec, 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[inf, 2.0]> (tolerance of <0.01>)\
+                   \n\t but was: <[inf, 1.0]> diverging at index <1> (<1.0> instead of <2.0>), \
+                   max absolute error of <1.0>"
+    )]
+    fn should_panic_if_floats_other_than_same_infinities_are_not_close() {
+        let test_vec = vec![f64::INFINITY, 1.0];
+        assert_that(&test_vec).is_close_to_each([f64::INFINITY, 2.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[1.0]> (tolerance of <0.01>)\
+                   \n\t but was: <[1.0, 2.0]> of length <2> instead of <1>"
+    )]
+    fn should_panic_if_floats_do_not_have_expected_length() {
+        let test_vec = vec![1.0f64, 2.0];
+        assert_that(&test_vec).is_close_to_each([1.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    fn should_not_panic_if_floats_are_relatively_close_to_each_expected() {
+        let test_vec = vec![1000.0f64, 0.001];
+        assert_that(&test_vec).is_relatively_close_to_each([1010.0, 0.00101], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[1000.0, 0.002]> (relative tolerance of <0.01>)\
+                   \n\t but was: <[1000.0, 0.001]> diverging at index <1> (<0.001> instead of <0.002>)"
+    )]
+    fn should_panic_if_floats_are_not_relatively_close_to_each_expected() {
+        let test_vec = vec![1000.0f64, 0.001];
+        assert_that(&test_vec).is_relatively_close_to_each([1000.0, 0.002], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[1.0, NaN]> (relative tolerance of <0.5>)\
+                   \n\t but was: <[1.0, 2.0]> diverging at index <1> (<2.0> instead of <NaN>), \
+                   max absolute error of <NaN>"
+    )]
+    fn should_panic_if_expected_floats_contain_nan() {
+        let test_vec = vec![1.0f64, 2.0];
+        assert_that(&test_vec).is_relatively_close_to_each([1.0, Float::nan()], 0.5);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[1.0]> (tolerance of <NaN>)\
+                   \n\t but was: <[1.0]>, with a NaN tolerance"
+    )]
+    fn should_panic_if_tolerance_is_nan() {
+        let test_vec = vec![1.0f64];
+        assert_that(&test_vec).is_close_to_each([1.0], f64::nan());
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    fn should_only_accept_exact_zero_if_relatively_close_to_zero() {
+        let test_vec = vec![0.0f64, -0.0];
+        assert_that(&test_vec).is_relatively_close_to_each([0.0, 0.0], 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    #[should_panic(
+        expected = "\n\texpected: floats close to <[0.0]> (relative tolerance of <0.5>)\
+                   \n\t but was: <[1e-12]> diverging at index <0> (<1e-12> instead of <0.0>)"
+    )]
+    fn should_panic_if_float_is_not_exactly_zero_when_relatively_close_to_zero() {
+        let test_vec = vec![1e-12f64];
+        assert_that(&test_vec).is_relatively_close_to_each([0.0], 0.5);
+    }
 }
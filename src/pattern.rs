@@ -0,0 +1,168 @@
+//! Wildcard patterns for strings: `*` matches any sequence of characters, `?` matches a single
+//! character and `\` escapes the next character.
+
+/// A parsed wildcard pattern, as the segments found between its `*` wildcards.
+pub(crate) struct Pattern {
+    segments: Vec<Segment>,
+}
+
+/// A part of a pattern without `*`, where `None` stands for a `?` wildcard.
+struct Segment {
+    source: String,
+    chars: Vec<Option<char>>,
+}
+
+impl Pattern {
+    /// Parses the provided pattern. Returns an error if it ends with a lone escape character.
+    pub(crate) fn parse(pattern: &str) -> Result<Pattern, String> {
+        let mut segments = vec![];
+        let mut segment = Segment::new();
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '*' => segments.push(std::mem::replace(&mut segment, Segment::new())),
+                '?' => segment.push(c, None),
+                '\\' => match chars.next() {
+                    Some(escaped) => {
+                        segment.source.push('\\');
+                        segment.push(escaped, Some(escaped));
+                    }
+                    None => return Err("trailing escape character".to_string()),
+                },
+                c => segment.push(c, Some(c)),
+            }
+        }
+
+        segments.push(segment);
+
+        Ok(Pattern { segments })
+    }
+
+    /// Describes why the subject does not match the pattern, naming the segment that could not
+    /// be found. Returns `None` if the subject matches.
+    ///
+    /// The first segment has to match at the start of the subject and the last one at its end.
+    /// Segments in between are matched at their leftmost position, which is enough to find a
+    /// match whenever there is one.
+    pub(crate) fn describe_mismatch(&self, subject: &str) -> Option<String> {
+        let subject: Vec<char> = subject.chars().collect();
+        let first = &self.segments[0];
+
+        if !first.matches_at(&subject, 0) {
+            return Some(format!("segment <{:?}> not found at start", first.source));
+        }
+
+        if self.segments.len() == 1 {
+            return (first.chars.len() != subject.len()).then(|| {
+                format!(
+                    "matching only up to index <{}> (unmatched rest <{:?}>)",
+                    first.chars.len(),
+                    subject[first.chars.len()..].iter().collect::<String>()
+                )
+            });
+        }
+
+        let mut cursor = first.chars.len();
+
+        for segment in &self.segments[1..self.segments.len() - 1] {
+            match segment.find_from(&subject, cursor) {
+                Some(position) => cursor = position + segment.chars.len(),
+                None => {
+                    return Some(format!(
+                        "segment <{:?}> not found after index <{}>",
+                        segment.source, cursor
+                    ))
+                }
+            }
+        }
+
+        let last = &self.segments[self.segments.len() - 1];
+        let last_start = subject.len().checked_sub(last.chars.len());
+
+        match last_start {
+            Some(start) if start >= cursor && last.matches_at(&subject, start) => None,
+            _ => Some(format!(
+                "segment <{:?}> not found at end after index <{}>",
+                last.source, cursor
+            )),
+        }
+    }
+}
+
+impl Segment {
+    fn new() -> Segment {
+        Segment {
+            source: String::new(),
+            chars: vec![],
+        }
+    }
+
+    fn push(&mut self, source: char, c: Option<char>) {
+        self.source.push(source);
+        self.chars.push(c);
+    }
+
+    fn matches_at(&self, subject: &[char], position: usize) -> bool {
+        subject.len() >= position + self.chars.len()
+            && self.chars.iter().zip(&subject[position..]).all(
+                |(expected, actual)| match expected {
+                    Some(expected) => expected == actual,
+                    None => true,
+                },
+            )
+    }
+
+    fn find_from(&self, subject: &[char], cursor: usize) -> Option<usize> {
+        (cursor..=subject.len()).find(|position| self.matches_at(subject, *position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mismatch(subject: &str, pattern: &str) -> Option<String> {
+        Pattern::parse(pattern).unwrap().describe_mismatch(subject)
+    }
+
+    #[test]
+    fn should_match_wildcards() {
+        assert_eq!(mismatch("request 12 took 30ms", "request * took *ms"), None);
+        assert_eq!(mismatch("abc", "a?c"), None);
+        assert_eq!(mismatch("abc", "*"), None);
+        assert_eq!(mismatch("", "*"), None);
+        assert_eq!(mismatch("abab", "*ab"), None);
+        assert_eq!(mismatch("a*b?", "a\\*b\\?"), None);
+        assert_eq!(mismatch("aXbXc", "a**c"), None);
+    }
+
+    #[test]
+    fn should_name_segment_not_found() {
+        assert_eq!(
+            mismatch("response 12", "request *"),
+            Some("segment <\"request \"> not found at start".to_string())
+        );
+        assert_eq!(
+            mismatch("a b c", "a * d * c"),
+            Some("segment <\" d \"> not found after index <2>".to_string())
+        );
+        assert_eq!(
+            mismatch("took 30s", "took *ms"),
+            Some("segment <\"ms\"> not found at end after index <5>".to_string())
+        );
+        assert_eq!(
+            mismatch("aba", "ab*ba"),
+            Some("segment <\"ba\"> not found at end after index <2>".to_string())
+        );
+        assert_eq!(
+            mismatch("abcd", "a?c"),
+            Some("matching only up to index <3> (unmatched rest <\"d\">)".to_string())
+        );
+    }
+
+    #[test]
+    fn should_reject_trailing_escape_character() {
+        assert!(Pattern::parse("abc\\").is_err());
+    }
+}
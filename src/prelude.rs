@@ -1,19 +1,39 @@
 pub use super::boolean::BooleanAssertions;
-pub use super::hashmap::{EntryHashMapAssertions, HashMapAssertions, KeyHashMapAssertions};
+#[allow(deprecated)]
+pub use super::hashmap::HashMapAssertions;
+pub use super::hashmap::{EntryHashMapAssertions, KeyHashMapAssertions, ValueHashMapAssertions};
+#[allow(deprecated)]
 pub use super::hashset::HashSetAssertions;
+pub use super::hashset::SetAlgebraAssertions;
 pub use super::iter::{
-    ContainingIntoIterAssertions, ContainingIteratorAssertions, MappingIterAssertions,
+    ConsumingIteratorAssertions, ContainingIntoIterAssertions, ContainingIteratorAssertions,
+    FlatteningIterAssertions, GroupingIterAssertions, HashedIterAssertions, MappingIterAssertions,
+    PositionalIterAssertions,
 };
 pub use super::numeric::OrderedAssertions;
 pub use super::option::{ContainingOptionAssertions, OptionAssertions};
 pub use super::path::PathAssertions;
 pub use super::result::{ContainingResultAssertions, ResultAssertions};
-pub use super::string::StrAssertions;
+pub use super::sized::SizedAssertions;
+pub use super::string::{
+    LineAssertions, LossyStrAssertions, ParsingAssertions, StrAssertions, SubstringAssertions,
+    Utf8Assertions,
+};
+#[allow(deprecated)]
 pub use super::vec::VecAssertions;
-pub use super::{assert_that, asserting};
+pub use super::{assert_that, assert_that_iter, asserting};
 
 #[cfg(feature = "num")]
-pub use super::numeric::FloatAssertions;
+pub use super::numeric::{FloatAssertions, FloatIterAssertions};
 
 #[cfg(feature = "json")]
 pub use super::json::{JsonAssertions, JsonObjectAssertions};
+
+#[cfg(feature = "serde")]
+pub use super::recursive::RecursiveAssertions;
+
+#[cfg(feature = "regex")]
+pub use super::regex::RegexAssertions;
+
+#[cfg(feature = "unicode")]
+pub use super::unicode::UnicodeStrAssertions;
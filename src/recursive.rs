@@ -0,0 +1,295 @@
+use crate::{AssertionFailure, Spec};
+use serde::Serialize;
+use serde_json::Value;
+
+pub trait RecursiveAssertions<T: ?Sized> {
+    #[track_caller]
+    fn is_recursively_equal_to(&mut self, expected: &T);
+
+    #[track_caller]
+    fn is_equal_to_ignoring(&mut self, expected: &T, ignored_fields: &[&str]);
+}
+
+impl<S> RecursiveAssertions<S> for Spec<'_, S>
+where
+    S: Serialize + ?Sized,
+{
+    /// Asserts that the subject is equal to the expected value, comparing them field by field.
+    /// Both values are walked through their `Serialize` implementation, and every differing
+    /// path is reported.
+    ///
+    /// # Examples
+    /// ```
+    /// # use serde::Serialize;
+    /// # use speculoos::prelude::*;
+    /// #
+    /// #[derive(Serialize)]
+    /// struct Line {
+    ///     price: f64,
+    /// }
+    ///
+    /// let actual = vec![Line { price: 1.5 }, Line { price: 2.0 }];
+    /// let expected = vec![Line { price: 1.5 }, Line { price: 2.0 }];
+    ///
+    /// assert_that!(actual).is_recursively_equal_to(&expected);
+    /// ```
+    fn is_recursively_equal_to(&mut self, expected: &S) {
+        check_recursively_equal(self, expected, &[]);
+    }
+
+    /// Asserts that the subject is equal to the expected value, comparing them field by field
+    /// and skipping the provided fields. A field is skipped either when its name or its full
+    /// path (like `lines[2].price`) is listed, at any depth.
+    ///
+    /// # Examples
+    /// ```
+    /// # use serde::Serialize;
+    /// # use speculoos::prelude::*;
+    /// #
+    /// #[derive(Serialize)]
+    /// struct Order {
+    ///     id: u64,
+    ///     created_at: String,
+    ///     total: u32,
+    /// }
+    ///
+    /// let actual = Order { id: 1, created_at: "today".to_string(), total: 42 };
+    /// let expected = Order { id: 2, created_at: "yesterday".to_string(), total: 42 };
+    ///
+    /// assert_that!(actual).is_equal_to_ignoring(&expected, &["id", "created_at"]);
+    /// ```
+    fn is_equal_to_ignoring(&mut self, expected: &S, ignored_fields: &[&str]) {
+        check_recursively_equal(self, expected, ignored_fields);
+    }
+}
+
+fn check_recursively_equal<S>(spec: &mut Spec<S>, expected: &S, ignored_fields: &[&str])
+where
+    S: Serialize + ?Sized,
+{
+    let (actual_value, expected_value) = match (
+        serde_json::to_value(spec.subject),
+        serde_json::to_value(expected),
+    ) {
+        (Ok(actual_value), Ok(expected_value)) => (actual_value, expected_value),
+        (Err(error), _) | (_, Err(error)) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected("values to be serializable".to_string())
+                .with_actual(format!("serialization error <{}>", error))
+                .fail();
+
+            unreachable!();
+        }
+    };
+
+    let mut differences = vec![];
+    collect_differences(
+        "",
+        &actual_value,
+        &expected_value,
+        ignored_fields,
+        &mut differences,
+    );
+
+    if differences.is_empty() {
+        return;
+    }
+
+    let expected_message = if ignored_fields.is_empty() {
+        "values to be recursively equal".to_string()
+    } else {
+        format!(
+            "values to be recursively equal, ignoring fields <{:?}>",
+            ignored_fields
+        )
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(expected_message)
+        .with_actual(format!("differences at:{}", differences.concat()))
+        .fail();
+}
+
+fn collect_differences(
+    path: &str,
+    actual: &Value,
+    expected: &Value,
+    ignored_fields: &[&str],
+    differences: &mut Vec<String>,
+) {
+    match (actual, expected) {
+        (Value::Object(actual_fields), Value::Object(expected_fields)) => {
+            for (field, expected_field) in expected_fields {
+                let field_path = field_path(path, field);
+
+                if is_ignored(field, &field_path, ignored_fields) {
+                    continue;
+                }
+
+                match actual_fields.get(field) {
+                    Some(actual_field) => collect_differences(
+                        &field_path,
+                        actual_field,
+                        expected_field,
+                        ignored_fields,
+                        differences,
+                    ),
+                    None => differences.push(format!(
+                        "\n\t\t{}: missing instead of <{}>",
+                        field_path, expected_field
+                    )),
+                }
+            }
+
+            for (field, actual_field) in actual_fields {
+                let field_path = field_path(path, field);
+
+                if !expected_fields.contains_key(field)
+                    && !is_ignored(field, &field_path, ignored_fields)
+                {
+                    differences.push(format!(
+                        "\n\t\t{}: <{}> instead of missing",
+                        field_path, actual_field
+                    ));
+                }
+            }
+        }
+        (Value::Array(actual_elements), Value::Array(expected_elements)) => {
+            for index in 0..actual_elements.len().max(expected_elements.len()) {
+                let element_path = format!("{}[{}]", path, index);
+
+                match (actual_elements.get(index), expected_elements.get(index)) {
+                    (Some(actual_element), Some(expected_element)) => collect_differences(
+                        &element_path,
+                        actual_element,
+                        expected_element,
+                        ignored_fields,
+                        differences,
+                    ),
+                    (None, Some(expected_element)) => differences.push(format!(
+                        "\n\t\t{}: missing instead of <{}>",
+                        element_path, expected_element
+                    )),
+                    (Some(actual_element), None) => differences.push(format!(
+                        "\n\t\t{}: <{}> instead of missing",
+                        element_path, actual_element
+                    )),
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+        _ if actual != expected => {
+            let path = if path.is_empty() { "<root>" } else { path };
+            differences.push(format!(
+                "\n\t\t{}: <{}> instead of <{}>",
+                path, actual, expected
+            ));
+        }
+        _ => {}
+    }
+}
+
+fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+fn is_ignored(field: &str, field_path: &str, ignored_fields: &[&str]) -> bool {
+    ignored_fields
+        .iter()
+        .any(|ignored| *ignored == field || *ignored == field_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Line {
+        product: &'static str,
+        price: f64,
+    }
+
+    #[derive(Serialize)]
+    struct Order {
+        id: u64,
+        created_at: &'static str,
+        lines: Vec<Line>,
+    }
+
+    fn order(id: u64, created_at: &'static str, prices: &[f64]) -> Order {
+        Order {
+            id,
+            created_at,
+            lines: prices
+                .iter()
+                .map(|price| Line {
+                    product: "tea",
+                    price: *price,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_values_are_recursively_equal() {
+        let actual = order(1, "today", &[1.5, 2.0]);
+        let expected = order(1, "today", &[1.5, 2.0]);
+
+        assert_that!(actual).is_recursively_equal_to(&expected);
+    }
+
+    #[test]
+    fn should_not_panic_if_only_ignored_fields_differ() {
+        let actual = order(1, "today", &[1.5, 2.0]);
+        let expected = order(2, "yesterday", &[1.5, 2.0]);
+
+        assert_that!(actual).is_equal_to_ignoring(&expected, &["id", "created_at"]);
+    }
+
+    #[test]
+    fn should_ignore_fields_by_full_path() {
+        let actual = order(1, "today", &[1.5, 2.0]);
+        let expected = order(1, "today", &[1.5, 3.0]);
+
+        assert_that!(actual).is_equal_to_ignoring(&expected, &["lines[1].price"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: values to be recursively equal\
+                   \n\t but was: differences at:\
+                   \n\t\tid: <1> instead of <2>\
+                   \n\t\tlines[1].price: <2.0> instead of <2.5>\
+                   \n\t\tlines[2]: missing instead of <{\"price\":3.0,\"product\":\"tea\"}>")]
+    fn should_panic_with_every_differing_path() {
+        let actual = order(1, "today", &[1.5, 2.0]);
+        let expected = order(2, "today", &[1.5, 2.5, 3.0]);
+
+        assert_that!(actual).is_recursively_equal_to(&expected);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: values to be recursively equal, ignoring fields <[\"id\"]>\
+                   \n\t but was: differences at:\
+                   \n\t\tcreated_at: <\"today\"> instead of <\"yesterday\">"
+    )]
+    fn should_panic_if_fields_other_than_ignored_differ() {
+        let actual = order(1, "today", &[]);
+        let expected = order(2, "yesterday", &[]);
+
+        assert_that!(actual).is_equal_to_ignoring(&expected, &["id"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: values to be recursively equal\
+                   \n\t but was: differences at:\
+                   \n\t\t<root>: <1> instead of <2>")]
+    fn should_panic_if_plain_values_differ() {
+        assert_that!(1).is_recursively_equal_to(&2);
+    }
+}
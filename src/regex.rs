@@ -0,0 +1,256 @@
+use crate::{leak, AssertionFailure, Spec};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+pub trait RegexAssertions<'s> {
+    #[track_caller]
+    fn matches_regex<E: AsRef<str>>(&mut self, pattern: E);
+
+    #[track_caller]
+    fn does_not_match_regex<E: AsRef<str>>(&mut self, pattern: E);
+
+    #[track_caller]
+    fn contains_match<E: AsRef<str>>(&mut self, pattern: E);
+
+    #[track_caller]
+    fn captures<E: AsRef<str>>(&mut self, pattern: E) -> Spec<'s, BTreeMap<&'s str, &'s str>>;
+}
+
+impl<'s, T> RegexAssertions<'s> for Spec<'s, T>
+where
+    T: AsRef<str> + ?Sized,
+{
+    /// Asserts that the whole subject string matches the provided regular expression.
+    ///
+    /// On failure, the message shows how much of the subject the pattern could match.
+    ///
+    /// # Examples
+    /// ```
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"2024-06-01").matches_regex(r"\d{4}-\d{2}-\d{2}");
+    /// ```
+    fn matches_regex<E: AsRef<str>>(&mut self, pattern: E) {
+        let subject = self.subject.as_ref();
+        let pattern = pattern.as_ref();
+        let whole_regex = compile(self, &format!("^(?:{})$", pattern), pattern);
+
+        if !whole_regex.is_match(subject) {
+            let regex = compile(self, pattern, pattern);
+
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("string matching regex <{}>", pattern))
+                .with_actual(format!(
+                    "<{:?}>, {}",
+                    subject,
+                    describe_partial_match(&regex, subject)
+                ))
+                .fail();
+        }
+    }
+
+    /// Asserts that the whole subject string does not match the provided regular expression.
+    ///
+    /// # Examples
+    /// ```
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"2024-06-01T10:00").does_not_match_regex(r"\d{4}-\d{2}-\d{2}");
+    /// ```
+    fn does_not_match_regex<E: AsRef<str>>(&mut self, pattern: E) {
+        let subject = self.subject.as_ref();
+        let pattern = pattern.as_ref();
+        let whole_regex = compile(self, &format!("^(?:{})$", pattern), pattern);
+
+        if whole_regex.is_match(subject) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("string not matching regex <{}>", pattern))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that some part of the subject string matches the provided regular expression.
+    ///
+    /// # Examples
+    /// ```
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"order #1234 shipped").contains_match(r"#\d+");
+    /// ```
+    fn contains_match<E: AsRef<str>>(&mut self, pattern: E) {
+        let subject = self.subject.as_ref();
+        let pattern = pattern.as_ref();
+        let regex = compile(self, pattern, pattern);
+
+        if !regex.is_match(subject) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("string containing a match for regex <{}>", pattern))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that some part of the subject string matches the provided regular expression.
+    ///
+    /// This will return a new `Spec` containing the named capture groups of the first match,
+    /// from group name to captured text, on which the map assertions can be used. Groups which
+    /// did not participate in the match are left out.
+    ///
+    /// # Examples
+    /// ```
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"released on 2024-06-01")
+    ///     .captures(r"(?P<year>\d{4})-(?P<month>\d{2})")
+    ///     .contains_entry("year", "2024");
+    /// ```
+    fn captures<E: AsRef<str>>(&mut self, pattern: E) -> Spec<'s, BTreeMap<&'s str, &'s str>> {
+        let subject: &'s str = self.subject.as_ref();
+        let pattern = pattern.as_ref();
+        let regex = compile(self, pattern, pattern);
+
+        let captures = match regex.captures(subject) {
+            Some(captures) => captures,
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected(format!("string containing a match for regex <{}>", pattern))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+
+                unreachable!();
+            }
+        };
+
+        let groups: BTreeMap<&'s str, &'s str> = regex
+            .capture_names()
+            .flatten()
+            .filter_map(|name| {
+                captures
+                    .name(name)
+                    .map(|group| (leak(name.to_string()).as_str(), group.as_str()))
+            })
+            .collect();
+
+        self.derive(leak(groups), self.subject_name)
+    }
+}
+
+fn compile<T>(spec: &Spec<T>, regex: &str, pattern: &str) -> Regex
+where
+    T: ?Sized,
+{
+    match Regex::new(regex) {
+        Ok(regex) => regex,
+        Err(error) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("a valid regex <{}>", pattern))
+                .with_actual(format!("{}", error))
+                .fail();
+
+            unreachable!();
+        }
+    }
+}
+
+/// Describes where matching the subject broke down: either the pattern matches a prefix of the
+/// subject only, some other part of it, or nothing at all.
+fn describe_partial_match(regex: &Regex, subject: &str) -> String {
+    let longest_match = regex
+        .find_iter(subject)
+        .max_by_key(|found| found.end() - found.start());
+
+    match longest_match {
+        Some(found) if found.start() == 0 => format!(
+            "matching only up to index <{}> (unmatched rest <{:?}>)",
+            found.end(),
+            &subject[found.end()..]
+        ),
+        Some(found) => format!(
+            "matching only <{:?}> at index <{}>",
+            found.as_str(),
+            found.start()
+        ),
+        None => "which has no match".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn should_not_panic_if_string_matches_regex() {
+        assert_that(&"2024-06-01").matches_regex(r"\d{4}-\d{2}-\d{2}");
+        assert_that(&"2024-06-01".to_string()).matches_regex(r"\d{4}-\d{2}-\d{2}");
+        assert_that(&"b").matches_regex("a|b");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string matching regex <\\d{4}-\\d{2}>\
+                   \n\t but was: <\"2024-06-01\">, matching only up to index <7> \
+                   (unmatched rest <\"-01\">)")]
+    fn should_panic_with_unmatched_rest_if_regex_matches_prefix() {
+        assert_that(&"2024-06-01").matches_regex(r"\d{4}-\d{2}");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string matching regex <\\d+>\
+                   \n\t but was: <\"order 1234\">, matching only <\"1234\"> at index <6>")]
+    fn should_panic_with_match_position_if_regex_matches_elsewhere() {
+        assert_that(&"order 1234").matches_regex(r"\d+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string matching regex <\\d+>\
+                   \n\t but was: <\"none\">, which has no match")]
+    fn should_panic_if_regex_does_not_match_at_all() {
+        assert_that(&"none").matches_regex(r"\d+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid regex <(unclosed>")]
+    fn should_panic_if_regex_is_invalid() {
+        assert_that(&"none").matches_regex("(unclosed");
+    }
+
+    #[test]
+    fn should_not_panic_if_string_does_not_match_regex() {
+        assert_that(&"order 1234").does_not_match_regex(r"\d+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string not matching regex <\\d+>\
+                   \n\t but was: <\"1234\">")]
+    fn should_panic_if_string_matches_regex_when_not_expected() {
+        assert_that(&"1234").does_not_match_regex(r"\d+");
+    }
+
+    #[test]
+    fn should_not_panic_if_string_contains_match() {
+        assert_that(&"order 1234 shipped").contains_match(r"\d+");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing a match for regex <\\d+>\
+                   \n\t but was: <\"none\">")]
+    fn should_panic_if_string_does_not_contain_match() {
+        assert_that(&"none").contains_match(r"\d+");
+    }
+
+    #[test]
+    fn should_allow_map_assertions_on_captures() {
+        let mut groups = assert_that(&"released on 2024-06")
+            .captures(r"(?P<year>\d{4})-(?P<month>\d{2})(?:-(?P<day>\d{2}))?");
+
+        groups.has_length(2);
+        groups.contains_entry("year", "2024");
+        groups.contains_key("month").is_equal_to("06");
+        groups.does_not_contain_key("day");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string containing a match for regex <(?P<year>\\d{4})>\
+                   \n\t but was: <\"no date\">"
+    )]
+    fn should_panic_if_captures_finds_no_match() {
+        assert_that(&"no date").captures(r"(?P<year>\d{4})");
+    }
+}
@@ -0,0 +1,401 @@
+use super::{AssertionFailure, Spec};
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+/// A collection which knows its number of elements.
+///
+/// This is implemented for the standard library collections, and can be implemented for your own
+/// types to make `SizedAssertions` available for them.
+pub trait Length {
+    /// The name of the collection, as displayed in failure messages.
+    const NAME: &'static str;
+
+    fn length(&self) -> usize;
+}
+
+pub trait SizedAssertions {
+    #[track_caller]
+    fn has_length(&mut self, expected: usize);
+    #[track_caller]
+    fn has_length_between(&mut self, min: usize, max: usize);
+    #[track_caller]
+    fn has_length_at_least(&mut self, min: usize);
+    #[track_caller]
+    fn has_length_at_most(&mut self, max: usize);
+    #[track_caller]
+    fn has_same_length_as<O: Length>(&mut self, other: O);
+    #[track_caller]
+    fn is_empty(&mut self);
+    #[track_caller]
+    fn is_not_empty(&mut self);
+}
+
+// Length assertions used to come from one trait per collection. `SizedAssertions` now provides
+// them for every `Length` type, and the former traits are kept as empty supertraits of it so that
+// imports and bounds naming them still compile.
+
+#[deprecated(note = "the length assertions of vectors are provided by `SizedAssertions`")]
+pub trait VecAssertions: SizedAssertions {}
+
+#[deprecated(note = "the length assertions of maps are provided by `SizedAssertions`")]
+pub trait HashMapAssertions<'s>: SizedAssertions {}
+
+#[deprecated(note = "the length assertions of sets are provided by `SizedAssertions`")]
+pub trait HashSetAssertions<'s>: SizedAssertions {}
+
+#[allow(deprecated)]
+impl<S: SizedAssertions + ?Sized> VecAssertions for S {}
+
+#[allow(deprecated)]
+impl<S: SizedAssertions + ?Sized> HashMapAssertions<'_> for S {}
+
+#[allow(deprecated)]
+impl<S: SizedAssertions + ?Sized> HashSetAssertions<'_> for S {}
+
+impl<T> SizedAssertions for Spec<'_, T>
+where
+    T: Length + ?Sized,
+{
+    /// Asserts that the length of the subject is equal to the provided length. The subject
+    /// type must implement `Length`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that!(vec![1, 2, 3, 4]).has_length(4);
+    /// ```
+    fn has_length(&mut self, expected: usize) {
+        let length = self.subject.length();
+
+        if length != expected {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("{} to have length <{}>", T::NAME, expected))
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
+    /// Asserts that the length of the subject is within the provided bounds, both inclusive.
+    /// The subject type must implement `Length`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&[1, 2, 3]).has_length_between(2, 3);
+    /// ```
+    fn has_length_between(&mut self, min: usize, max: usize) {
+        let length = self.subject.length();
+
+        if length < min || length > max {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "{} to have length between <{}> and <{}>",
+                    T::NAME,
+                    min,
+                    max
+                ))
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
+    /// Asserts that the length of the subject is greater than or equal to the provided length.
+    /// The subject type must implement `Length`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::VecDeque;
+    /// let test_deque: VecDeque<u8> = VecDeque::from(vec![1, 2, 3]);
+    /// assert_that(&test_deque).has_length_at_least(2);
+    /// ```
+    fn has_length_at_least(&mut self, min: usize) {
+        let length = self.subject.length();
+
+        if length < min {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("{} to have length at least <{}>", T::NAME, min))
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
+    /// Asserts that the length of the subject is less than or equal to the provided length.
+    /// The subject type must implement `Length`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::collections::BTreeSet;
+    /// let test_set: BTreeSet<u8> = [1, 2].into_iter().collect();
+    /// assert_that(&test_set).has_length_at_most(2);
+    /// ```
+    fn has_length_at_most(&mut self, max: usize) {
+        let length = self.subject.length();
+
+        if length > max {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("{} to have length at most <{}>", T::NAME, max))
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject has as many elements as the provided collection. Both types
+    /// must implement `Length`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec = vec![1, 2, 3];
+    /// assert_that(&test_vec).has_same_length_as(&["a", "b", "c"]);
+    /// ```
+    fn has_same_length_as<O: Length>(&mut self, other: O) {
+        let length = self.subject.length();
+        let other_length = other.length();
+
+        if length != other_length {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!(
+                    "{} to have length <{}> (same as {})",
+                    T::NAME,
+                    other_length,
+                    O::NAME
+                ))
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject is empty. The subject type must implement `Length`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec: Vec<u8> = vec![];
+    /// assert_that!(test_vec).is_empty();
+    /// ```
+    fn is_empty(&mut self) {
+        let length = self.subject.length();
+
+        if length != 0 {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("an empty {}", T::NAME))
+                .with_actual(format!("a {} with length <{:?}>", T::NAME, length))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject is not empty. The subject type must implement `Length`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let test_vec: Vec<u8> = vec![1];
+    /// assert_that!(test_vec).is_not_empty();
+    /// ```
+    fn is_not_empty(&mut self) {
+        if self.subject.length() == 0 {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("a non empty {}", T::NAME))
+                .with_actual(format!("an empty {}", T::NAME))
+                .fail();
+        }
+    }
+}
+
+impl<T: Length + ?Sized> Length for &T {
+    const NAME: &'static str = T::NAME;
+
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+impl<T: Length + ?Sized> Length for Box<T> {
+    const NAME: &'static str = T::NAME;
+
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    const NAME: &'static str = "vec";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for [T] {
+    const NAME: &'static str = "slice";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> Length for [T; N] {
+    const NAME: &'static str = "array";
+
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<T> Length for VecDeque<T> {
+    const NAME: &'static str = "VecDeque";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for LinkedList<T> {
+    const NAME: &'static str = "LinkedList";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for BinaryHeap<T> {
+    const NAME: &'static str = "BinaryHeap";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, S> Length for HashSet<K, S> {
+    const NAME: &'static str = "HashSet";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K> Length for BTreeSet<K> {
+    const NAME: &'static str = "BTreeSet";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> Length for HashMap<K, V, S> {
+    const NAME: &'static str = "hashmap";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> Length for BTreeMap<K, V> {
+    const NAME: &'static str = "BTreeMap";
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::needless_borrows_for_generic_args)]
+    use super::super::prelude::*;
+
+    use std::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+
+    #[test]
+    fn should_not_panic_if_std_collections_length_matches_expected() {
+        let test_deque: VecDeque<u8> = VecDeque::from(vec![1, 2]);
+        let test_list: LinkedList<u8> = [1, 2].into_iter().collect();
+        let test_heap: BinaryHeap<u8> = [1, 2].into_iter().collect();
+        let test_set: BTreeSet<u8> = [1, 2].into_iter().collect();
+        let test_map: BTreeMap<u8, u8> = [(1, 1), (2, 2)].into_iter().collect();
+
+        assert_that(&test_deque).has_length(2);
+        assert_that(&test_list).has_length(2);
+        assert_that(&test_heap).has_length(2);
+        assert_that(&test_set).has_length(2);
+        assert_that(&test_map).has_length(2);
+    }
+
+    #[test]
+    fn should_not_panic_if_slice_length_matches_expected() {
+        let test_array = [1, 2, 3];
+        let test_slice: &[u8] = &[1, 2, 3];
+        let test_box: Box<[u8]> = Box::new([1, 2, 3]);
+
+        assert_that(&test_array).has_length(3);
+        assert_that(&test_array[1..]).has_length(2);
+        assert_that(&test_slice).has_length(3);
+        assert_that(&test_box).has_length(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: VecDeque to have length <1>\n\t but was: <2>")]
+    fn should_panic_if_deque_length_does_not_match_expected() {
+        let test_deque: VecDeque<u8> = VecDeque::from(vec![1, 2]);
+        assert_that(&test_deque).has_length(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an empty BTreeMap\
+                   \n\t but was: a BTreeMap with length <1>")]
+    fn should_panic_if_btree_map_was_expected_to_be_empty_and_is_not() {
+        let test_map: BTreeMap<u8, u8> = [(1, 1)].into_iter().collect();
+        assert_that(&test_map).is_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a non empty slice\n\t but was: an empty slice")]
+    fn should_panic_if_slice_was_expected_to_not_be_empty_and_is() {
+        let test_slice: &[u8] = &[];
+        assert_that(&test_slice).is_not_empty();
+    }
+
+    #[test]
+    fn should_not_panic_if_length_is_within_bounds() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).has_length_between(3, 3);
+        assert_that(&test_vec).has_length_between(1, 5);
+        assert_that(&test_vec).has_length_at_least(3);
+        assert_that(&test_vec).has_length_at_most(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have length between <4> and <6>\
+                   \n\t but was: <3>")]
+    fn should_panic_if_length_is_not_between_bounds() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).has_length_between(4, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: array to have length at least <4>\n\t but was: <3>")]
+    fn should_panic_if_length_is_less_than_minimum() {
+        assert_that(&[1, 2, 3]).has_length_at_least(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: LinkedList to have length at most <1>\
+                   \n\t but was: <2>")]
+    fn should_panic_if_length_is_more_than_maximum() {
+        let test_list: LinkedList<u8> = [1, 2].into_iter().collect();
+        assert_that(&test_list).has_length_at_most(1);
+    }
+
+    #[test]
+    fn should_not_panic_if_length_is_same_as_other() {
+        let test_vec = vec![1, 2, 3];
+        let test_set: BTreeSet<&str> = ["a", "b", "c"].into_iter().collect();
+
+        assert_that(&test_vec).has_same_length_as(&test_set);
+        assert_that(&test_vec).has_same_length_as(["a", "b", "c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have length <2> (same as BTreeSet)\
+                   \n\t but was: <3>")]
+    fn should_panic_if_length_is_not_same_as_other() {
+        let test_vec = vec![1, 2, 3];
+        let test_set: BTreeSet<&str> = ["a", "b"].into_iter().collect();
+
+        assert_that(&test_vec).has_same_length_as(&test_set);
+    }
+}
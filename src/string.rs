@@ -1,8 +1,23 @@
-use super::{AssertionFailure, DescriptiveSpec, Spec};
+use super::diff::describe_difference;
+use super::pattern::Pattern;
+use super::{leak, AssertionFailure, DescriptiveSpec, Spec};
 
-use std::borrow::Borrow;
+use std::any::type_name;
+use std::borrow::{Borrow, Cow};
+use std::ffi::{OsStr, OsString};
+use std::fmt::Debug;
+use std::ops::{Bound, RangeBounds};
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
 
-pub trait StrAssertions<T> {
+/// A value which converts to a string, replacing anything which is not valid UTF-8 with
+/// `U+FFFD REPLACEMENT CHARACTER`. Such values reach the string assertions with `as_lossy_str`.
+pub trait LossyStr {
+    fn to_str_lossy(&self) -> Cow<'_, str>;
+}
+
+pub trait StrAssertions<T: ?Sized> {
     #[track_caller]
     fn starts_with<E: AsRef<str>>(&mut self, expected: E);
     #[track_caller]
@@ -12,12 +27,99 @@ pub trait StrAssertions<T> {
     #[track_caller]
     fn does_not_contain<E: AsRef<str>>(&mut self, expected: E);
     #[track_caller]
+    fn contains_times<E: AsRef<str>>(&mut self, expected: E, times: usize);
+    #[track_caller]
+    fn contains_at_least<E: AsRef<str>>(&mut self, expected: E, times: usize);
+    #[track_caller]
+    fn contains_at_most<E: AsRef<str>>(&mut self, expected: E, times: usize);
+    #[track_caller]
+    fn contains_in_order<I, E>(&mut self, expected: I)
+    where
+        I: IntoIterator<Item = E>,
+        E: AsRef<str>;
+    #[track_caller]
     fn is_empty(&mut self);
+    #[track_caller]
+    fn is_equal_to_str<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn is_equal_to_ignoring_case<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn is_equal_to_ignoring_whitespace<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn is_equal_to_normalizing_whitespace<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn is_equal_to_ignoring_newlines<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn contains_ignoring_case<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn starts_with_ignoring_case<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn matches_pattern<E: AsRef<str>>(&mut self, pattern: E);
+    #[track_caller]
+    fn has_length(&mut self, expected: usize);
+    #[track_caller]
+    fn has_length_in_chars(&mut self, expected: usize);
+    #[track_caller]
+    fn has_length_in_bytes(&mut self, expected: usize);
+    #[track_caller]
+    fn is_ascii(&mut self);
+    #[track_caller]
+    fn is_alphanumeric(&mut self);
+    #[track_caller]
+    fn is_uppercase(&mut self);
+    #[track_caller]
+    fn is_lowercase(&mut self);
+    #[track_caller]
+    fn is_blank(&mut self);
+}
+
+pub trait ParsingAssertions<'s> {
+    #[track_caller]
+    fn parses_as<U>(&mut self) -> Spec<'s, U>
+    where
+        U: FromStr + 's,
+        U::Err: Debug;
+}
+
+pub trait LossyStrAssertions<'s> {
+    fn as_lossy_str(&mut self) -> Spec<'s, &'s str>;
+}
+
+pub trait Utf8Assertions<'s> {
+    #[track_caller]
+    fn is_valid_utf8(&mut self) -> Spec<'s, &'s str>;
+}
+
+pub trait LineAssertions<'s> {
+    fn lines(&mut self) -> Spec<'s, Vec<&'s str>>;
+    #[track_caller]
+    fn has_line_count(&mut self, expected: usize);
+    #[track_caller]
+    fn contains_line<E: AsRef<str>>(&mut self, expected: E);
+    #[track_caller]
+    fn line(&mut self, number: usize) -> Spec<'s, &'s str>;
+    #[track_caller]
+    fn is_equal_to_trimmed_lines<E: AsRef<str>>(&mut self, expected: E);
+}
+
+pub trait SubstringAssertions<'s> {
+    #[track_caller]
+    fn strip_prefix<E: AsRef<str>>(&mut self, prefix: E) -> Spec<'s, &'s str>;
+    #[track_caller]
+    fn strip_suffix<E: AsRef<str>>(&mut self, suffix: E) -> Spec<'s, &'s str>;
+    #[track_caller]
+    fn after<E: AsRef<str>>(&mut self, marker: E) -> Spec<'s, &'s str>;
+    #[track_caller]
+    fn before<E: AsRef<str>>(&mut self, marker: E) -> Spec<'s, &'s str>;
+    #[track_caller]
+    fn between<E: AsRef<str>, F: AsRef<str>>(&mut self, start: E, end: F) -> Spec<'s, &'s str>;
+    #[track_caller]
+    fn substring<R: RangeBounds<usize> + Debug>(&mut self, range: R) -> Spec<'s, &'s str>;
 }
 
 impl<T> StrAssertions<T> for Spec<'_, T>
 where
-    T: AsRef<str>,
+    T: AsRef<str> + ?Sized,
 {
     /// Asserts that the subject `&str` starts with the provided `&str`.
     ///
@@ -63,6 +165,84 @@ where
         does_not_contain(self, subject, expected.as_ref());
     }
 
+    /// Asserts that the subject `&str` contains the provided `&str` exactly the provided number
+    /// of times. Occurrences are counted without overlapping.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"ERROR a\nWARN b\nERROR c").contains_times("ERROR", 2);
+    /// ```
+    fn contains_times<E: AsRef<str>>(&mut self, expected: E, times: usize) {
+        let subject = self.subject.as_ref();
+        contains_count(
+            self,
+            subject,
+            expected.as_ref(),
+            times,
+            "exactly",
+            |count, times| count == times,
+        );
+    }
+
+    /// Asserts that the subject `&str` contains the provided `&str` at least the provided number
+    /// of times. Occurrences are counted without overlapping.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"retry, retry, retry").contains_at_least("retry", 2);
+    /// ```
+    fn contains_at_least<E: AsRef<str>>(&mut self, expected: E, times: usize) {
+        let subject = self.subject.as_ref();
+        contains_count(
+            self,
+            subject,
+            expected.as_ref(),
+            times,
+            "at least",
+            |count, times| count >= times,
+        );
+    }
+
+    /// Asserts that the subject `&str` contains the provided `&str` at most the provided number
+    /// of times. Occurrences are counted without overlapping.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"WARN a\nINFO b").contains_at_most("WARN", 1);
+    /// ```
+    fn contains_at_most<E: AsRef<str>>(&mut self, expected: E, times: usize) {
+        let subject = self.subject.as_ref();
+        contains_count(
+            self,
+            subject,
+            expected.as_ref(),
+            times,
+            "at most",
+            |count, times| count <= times,
+        );
+    }
+
+    /// Asserts that the subject `&str` contains all of the provided `&str`, in the provided
+    /// order and without overlapping.
+    ///
+    /// On failure, the message shows which part could not be found after the previous one.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"BEGIN; do work; COMMIT").contains_in_order(["BEGIN", "work", "COMMIT"]);
+    /// ```
+    fn contains_in_order<I, E>(&mut self, expected: I)
+    where
+        I: IntoIterator<Item = E>,
+        E: AsRef<str>,
+    {
+        let subject = self.subject.as_ref();
+        let expected: Vec<E> = expected.into_iter().collect();
+        let expected: Vec<&str> = expected.iter().map(AsRef::as_ref).collect();
+
+        contains_in_order(self, subject, &expected);
+    }
+
     /// Asserts that the subject `&str` is empty.
     ///
     /// ```rust
@@ -73,6 +253,669 @@ where
         let subject = self.subject.as_ref();
         is_empty(self, subject);
     }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str`.
+    ///
+    /// Unlike `is_equal_to`, the failure message points at the first differing character and,
+    /// for multi-line strings, shows a line diff.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"hello".to_string()).is_equal_to_str("hello");
+    /// ```
+    fn is_equal_to_str<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        is_equal_to_str(self, subject, expected.as_ref());
+    }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str`, ignoring case.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"SELECT * FROM users").is_equal_to_ignoring_case("select * from Users");
+    /// ```
+    fn is_equal_to_ignoring_case<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        is_equal_normalized(
+            self,
+            subject,
+            expected.as_ref(),
+            str::to_lowercase,
+            "ignoring case",
+        );
+    }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str`, ignoring all whitespace.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"<p> Hello </p>").is_equal_to_ignoring_whitespace("<p>Hello</p>");
+    /// ```
+    fn is_equal_to_ignoring_whitespace<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        is_equal_normalized(
+            self,
+            subject,
+            expected.as_ref(),
+            remove_whitespace,
+            "ignoring whitespace",
+        );
+    }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str` once leading and trailing
+    /// whitespace is trimmed and every other run of whitespace is replaced by a single space.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&" SELECT *\n  FROM users ").is_equal_to_normalizing_whitespace("SELECT * FROM users");
+    /// ```
+    fn is_equal_to_normalizing_whitespace<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        is_equal_normalized(
+            self,
+            subject,
+            expected.as_ref(),
+            normalize_whitespace,
+            "normalizing whitespace",
+        );
+    }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str`, treating `\r\n`, `\r`
+    /// and `\n` line endings as the same.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"first\r\nsecond").is_equal_to_ignoring_newlines("first\nsecond");
+    /// ```
+    fn is_equal_to_ignoring_newlines<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        is_equal_normalized(
+            self,
+            subject,
+            expected.as_ref(),
+            normalize_newlines,
+            "ignoring newlines",
+        );
+    }
+
+    /// Asserts that the subject `&str` contains the provided `&str`, ignoring case.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"ERROR: disk full").contains_ignoring_case("Disk Full");
+    /// ```
+    fn contains_ignoring_case<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        contains_ignoring_case(self, subject, expected.as_ref());
+    }
+
+    /// Asserts that the subject `&str` starts with the provided `&str`, ignoring case.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"WARN something happened").starts_with_ignoring_case("warn");
+    /// ```
+    fn starts_with_ignoring_case<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        starts_with_ignoring_case(self, subject, expected.as_ref());
+    }
+
+    /// Asserts that the subject `&str` matches the provided wildcard pattern, where `*` matches
+    /// any sequence of characters and `?` matches a single character. Either wildcard can be
+    /// matched literally by escaping it with a `\`.
+    ///
+    /// On failure, the message names the literal segment of the pattern which could not be found.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"request 42 took 13ms").matches_pattern("request * took *ms");
+    /// assert_that(&"is it *done*?").matches_pattern("is it \\*?one\\*\\?");
+    /// ```
+    fn matches_pattern<E: AsRef<str>>(&mut self, pattern: E) {
+        let subject = self.subject.as_ref();
+        matches_pattern(self, subject, pattern.as_ref());
+    }
+
+    /// Shorthand for `has_length_in_chars`, so that strings answer `has_length` like the
+    /// collections of `SizedAssertions` do.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"héllo".to_string()).has_length(5);
+    /// ```
+    fn has_length(&mut self, expected: usize) {
+        self.has_length_in_chars(expected);
+    }
+
+    /// Asserts that the subject `&str` has the provided number of `char`s (Unicode scalar
+    /// values).
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"héllo").has_length_in_chars(5);
+    /// ```
+    fn has_length_in_chars(&mut self, expected: usize) {
+        let subject = self.subject.as_ref();
+        has_length(self, subject, expected, subject.chars().count(), "chars");
+    }
+
+    /// Asserts that the subject `&str` has the provided length in bytes, once UTF-8 encoded.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"héllo").has_length_in_bytes(6);
+    /// ```
+    fn has_length_in_bytes(&mut self, expected: usize) {
+        let subject = self.subject.as_ref();
+        has_length(self, subject, expected, subject.len(), "bytes");
+    }
+
+    /// Asserts that the subject `&str` only contains ASCII characters.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"hello").is_ascii();
+    /// ```
+    fn is_ascii(&mut self) {
+        let subject = self.subject.as_ref();
+        all_chars_match(self, subject, |c| c.is_ascii(), "an ASCII string");
+    }
+
+    /// Asserts that the subject `&str` only contains alphanumeric characters, as defined by
+    /// Unicode.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"héllo42").is_alphanumeric();
+    /// ```
+    fn is_alphanumeric(&mut self) {
+        let subject = self.subject.as_ref();
+        all_chars_match(
+            self,
+            subject,
+            char::is_alphanumeric,
+            "an alphanumeric string",
+        );
+    }
+
+    /// Asserts that the subject `&str` contains no lowercase character. Characters without a
+    /// case, such as digits or punctuation, are allowed.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"ÉCOLE N°1").is_uppercase();
+    /// ```
+    fn is_uppercase(&mut self) {
+        let subject = self.subject.as_ref();
+        all_chars_match(self, subject, |c| !c.is_lowercase(), "an uppercase string");
+    }
+
+    /// Asserts that the subject `&str` contains no uppercase character. Characters without a
+    /// case, such as digits or punctuation, are allowed.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"école n°1").is_lowercase();
+    /// ```
+    fn is_lowercase(&mut self) {
+        let subject = self.subject.as_ref();
+        all_chars_match(self, subject, |c| !c.is_uppercase(), "a lowercase string");
+    }
+
+    /// Asserts that the subject `&str` is empty or only contains whitespace.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&" \t\n").is_blank();
+    /// ```
+    fn is_blank(&mut self) {
+        let subject = self.subject.as_ref();
+
+        if !subject.chars().all(char::is_whitespace) {
+            AssertionFailure::from_spec(self)
+                .with_expected("a blank string".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+    }
+}
+
+impl<'s, T> LineAssertions<'s> for Spec<'s, T>
+where
+    T: AsRef<str> + ?Sized,
+{
+    /// Returns a new `Spec` over the lines of the subject `&str`, without their line endings,
+    /// on which any iterable assertion can be used.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let output = "Compiling\nFinished";
+    /// assert_that(&output).lines().contains("Finished");
+    /// ```
+    fn lines(&mut self) -> Spec<'s, Vec<&'s str>> {
+        let subject: &'s str = self.subject.as_ref();
+        let lines: Vec<&'s str> = subject.lines().collect();
+
+        self.derive(leak(lines), Some(self.nested_name("string", ".lines()")))
+    }
+
+    /// Asserts that the subject `&str` has the provided number of lines.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"Compiling\nFinished\n").has_line_count(2);
+    /// ```
+    fn has_line_count(&mut self, expected: usize) {
+        let subject = self.subject.as_ref();
+        let line_count = subject.lines().count();
+
+        if line_count != expected {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("string with <{}> lines", expected))
+                .with_actual(format!("<{}> lines in <{:?}>", line_count, subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that one of the lines of the subject `&str` is equal to the provided `&str`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"Compiling\nFinished").contains_line("Finished");
+    /// ```
+    fn contains_line<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        let expected = expected.as_ref();
+
+        if !subject.lines().any(|line| line == expected) {
+            let lines: Vec<&str> = subject.lines().collect();
+
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("string containing line <{:?}>", expected))
+                .with_actual(format!("<{:?}>", lines))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject `&str` has a line with the provided number, starting from 1.
+    ///
+    /// This will return a new `Spec` containing the line, without its line ending.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"Compiling\nFinished").line(2).starts_with("Fin");
+    /// ```
+    fn line(&mut self, number: usize) -> Spec<'s, &'s str> {
+        let subject: &'s str = self.subject.as_ref();
+
+        if let Some(line) = number
+            .checked_sub(1)
+            .and_then(|index| subject.lines().nth(index))
+        {
+            let name = self.nested_name("string", &format!(".line({})", number));
+            return self.derive(leak(line), Some(name));
+        }
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("string with a line <{}>", number))
+            .with_actual(format!(
+                "<{}> lines in <{:?}>",
+                subject.lines().count(),
+                subject
+            ))
+            .fail();
+
+        unreachable!();
+    }
+
+    /// Asserts that the subject `&str` has the same lines as the provided `&str`, once leading
+    /// and trailing whitespace is trimmed from each line. Line endings are ignored too.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let output = "  name: speculoos  \r\n  version: 1";
+    /// assert_that(&output).is_equal_to_trimmed_lines("name: speculoos\nversion: 1");
+    /// ```
+    fn is_equal_to_trimmed_lines<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        is_equal_normalized(
+            self,
+            subject,
+            expected.as_ref(),
+            trim_lines,
+            "with trimmed lines",
+        );
+    }
+}
+
+impl<'s, T> SubstringAssertions<'s> for Spec<'s, T>
+where
+    T: AsRef<str> + ?Sized,
+{
+    /// Asserts that the subject `&str` starts with the provided `&str`.
+    ///
+    /// This will return a new `Spec` containing the rest of the subject, named after the
+    /// subject as in `string.strip_prefix("Bearer ")`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"Bearer abc123").strip_prefix("Bearer ").is_equal_to("abc123");
+    /// ```
+    fn strip_prefix<E: AsRef<str>>(&mut self, prefix: E) -> Spec<'s, &'s str> {
+        let subject: &'s str = self.subject.as_ref();
+        let prefix = prefix.as_ref();
+
+        match subject.strip_prefix(prefix) {
+            Some(rest) => self.derive_substring(rest, &format!(".strip_prefix({:?})", prefix)),
+            None => {
+                starts_with(self, subject, prefix);
+                unreachable!();
+            }
+        }
+    }
+
+    /// Asserts that the subject `&str` ends with the provided `&str`.
+    ///
+    /// This will return a new `Spec` containing the rest of the subject, named after the
+    /// subject as in `string.strip_suffix(".rs")`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"main.rs").strip_suffix(".rs").is_equal_to("main");
+    /// ```
+    fn strip_suffix<E: AsRef<str>>(&mut self, suffix: E) -> Spec<'s, &'s str> {
+        let subject: &'s str = self.subject.as_ref();
+        let suffix = suffix.as_ref();
+
+        match subject.strip_suffix(suffix) {
+            Some(rest) => self.derive_substring(rest, &format!(".strip_suffix({:?})", suffix)),
+            None => {
+                ends_with(self, subject, suffix);
+                unreachable!();
+            }
+        }
+    }
+
+    /// Asserts that the subject `&str` contains the provided marker.
+    ///
+    /// This will return a new `Spec` containing the part of the subject after the first
+    /// occurrence of the marker, named after the subject as in `string.after(": ")`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"status: ok").after(": ").is_equal_to("ok");
+    /// ```
+    fn after<E: AsRef<str>>(&mut self, marker: E) -> Spec<'s, &'s str> {
+        let subject: &'s str = self.subject.as_ref();
+        let marker = marker.as_ref();
+
+        match subject.split_once(marker) {
+            Some((_, rest)) => self.derive_substring(rest, &format!(".after({:?})", marker)),
+            None => {
+                contains(self, subject, marker);
+                unreachable!();
+            }
+        }
+    }
+
+    /// Asserts that the subject `&str` contains the provided marker.
+    ///
+    /// This will return a new `Spec` containing the part of the subject before the first
+    /// occurrence of the marker, named after the subject as in `string.before(": ")`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"status: ok").before(": ").is_equal_to("status");
+    /// ```
+    fn before<E: AsRef<str>>(&mut self, marker: E) -> Spec<'s, &'s str> {
+        let subject: &'s str = self.subject.as_ref();
+        let marker = marker.as_ref();
+
+        match subject.split_once(marker) {
+            Some((start, _)) => self.derive_substring(start, &format!(".before({:?})", marker)),
+            None => {
+                contains(self, subject, marker);
+                unreachable!();
+            }
+        }
+    }
+
+    /// Asserts that the subject `&str` contains the provided start marker, followed by the
+    /// provided end marker.
+    ///
+    /// This will return a new `Spec` containing the part of the subject between the first
+    /// occurrence of the start marker and the next occurrence of the end marker, named after the
+    /// subject as in `string.between("(", ")")`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"took 42ms (cached)").between("(", ")").is_equal_to("cached");
+    /// ```
+    fn between<E: AsRef<str>, F: AsRef<str>>(&mut self, start: E, end: F) -> Spec<'s, &'s str> {
+        let subject: &'s str = self.subject.as_ref();
+        let (start, end) = (start.as_ref(), end.as_ref());
+
+        let rest = match subject.split_once(start) {
+            Some((_, rest)) => rest,
+            None => {
+                contains(self, subject, start);
+                unreachable!();
+            }
+        };
+
+        match rest.split_once(end) {
+            Some((inner, _)) => {
+                self.derive_substring(inner, &format!(".between({:?}, {:?})", start, end))
+            }
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected(format!("string containing <{:?}> after <{:?}>", end, start))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+
+                unreachable!();
+            }
+        }
+    }
+
+    /// Asserts that the provided range of byte indices is within the subject `&str`, on char
+    /// boundaries.
+    ///
+    /// This will return a new `Spec` containing that part of the subject, named after the
+    /// subject as in `string.substring(0..4)`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"2024-06-01").substring(..4).is_equal_to("2024");
+    /// ```
+    fn substring<R: RangeBounds<usize> + Debug>(&mut self, range: R) -> Spec<'s, &'s str> {
+        let subject: &'s str = self.subject.as_ref();
+
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => subject.len(),
+        };
+
+        if let Some(part) = subject.get(start..end) {
+            return self.derive_substring(part, &format!(".substring({:?})", range));
+        }
+
+        let reason = if start > end || end > subject.len() {
+            format!("with length <{}>", subject.len())
+        } else {
+            let index = if subject.is_char_boundary(start) {
+                end
+            } else {
+                start
+            };
+
+            format!("with index <{}> not on a char boundary", index)
+        };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("string containing byte range <{:?}>", range))
+            .with_actual(format!("<{:?}>, {}", subject, reason))
+            .fail();
+
+        unreachable!();
+    }
+}
+
+impl<'s, T> Spec<'s, T>
+where
+    T: AsRef<str> + ?Sized,
+{
+    fn derive_substring(&self, part: &'s str, accessor: &str) -> Spec<'s, &'s str> {
+        self.derive(leak(part), Some(self.nested_name("string", accessor)))
+    }
+}
+
+impl<'s, T> ParsingAssertions<'s> for Spec<'s, T>
+where
+    T: AsRef<str> + ?Sized,
+{
+    /// Asserts that the subject `&str` can be parsed into a `U` with `FromStr`.
+    ///
+    /// This will return a new `Spec` containing the parsed value, named after the subject as in
+    /// `string.parse::<u32>()`.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::net::IpAddr;
+    /// assert_that(&"42").parses_as::<u32>().is_greater_than(40);
+    /// assert_that(&"::1").parses_as::<IpAddr>().matches(|ip| ip.is_loopback());
+    /// ```
+    fn parses_as<U>(&mut self) -> Spec<'s, U>
+    where
+        U: FromStr + 's,
+        U::Err: Debug,
+    {
+        let subject = self.subject.as_ref();
+        let target = short_type_name::<U>();
+
+        match subject.parse::<U>() {
+            Ok(value) => {
+                let accessor = format!(".parse::<{}>()", target);
+                self.derive(leak(value), Some(self.nested_name("string", &accessor)))
+            }
+            Err(error) => {
+                AssertionFailure::from_spec(self)
+                    .with_expected(format!("string parsing as <{}>", target))
+                    .with_actual(format!("<{:?}>, failing with <{:?}>", subject, error))
+                    .fail();
+
+                unreachable!();
+            }
+        }
+    }
+}
+
+impl<'s, T> Utf8Assertions<'s> for Spec<'s, T>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    /// Asserts that the subject bytes are valid UTF-8.
+    ///
+    /// This will return a new `Spec` containing the decoded `&str`, on which the string
+    /// assertions can be used.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// let body: &[u8] = b"{\"status\": \"ok\"}";
+    /// assert_that(&body).is_valid_utf8().contains("ok");
+    /// ```
+    fn is_valid_utf8(&mut self) -> Spec<'s, &'s str> {
+        let bytes: &'s [u8] = self.subject.as_ref();
+
+        match std::str::from_utf8(bytes) {
+            Ok(decoded) => self.derive(leak(decoded), self.subject_name),
+            Err(error) => {
+                AssertionFailure::from_spec(self)
+                    .with_expected("bytes to be valid UTF-8".to_string())
+                    .with_actual(format!(
+                        "<b\"{}\">, with invalid byte sequence at index <{}>",
+                        bytes.escape_ascii(),
+                        error.valid_up_to()
+                    ))
+                    .fail();
+
+                unreachable!();
+            }
+        }
+    }
+}
+
+impl<'s, T> LossyStrAssertions<'s> for Spec<'s, T>
+where
+    T: LossyStr + ?Sized,
+{
+    /// Converts the subject to a `&str`, replacing anything which is not valid UTF-8.
+    ///
+    /// This will return a new `Spec` containing the converted `&str`, on which the string
+    /// assertions can be used.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// # use std::ffi::OsStr;
+    /// assert_that(&'x').as_lossy_str().is_equal_to_ignoring_case("X");
+    /// assert_that(OsStr::new("file.txt")).as_lossy_str().ends_with(".txt");
+    /// ```
+    fn as_lossy_str(&mut self) -> Spec<'s, &'s str> {
+        let subject: &'s str = match self.subject.to_str_lossy() {
+            Cow::Borrowed(value) => value,
+            Cow::Owned(value) => leak(value).as_str(),
+        };
+
+        self.derive(leak(subject), self.subject_name)
+    }
+}
+
+impl LossyStr for char {
+    fn to_str_lossy(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl LossyStr for OsStr {
+    fn to_str_lossy(&self) -> Cow<'_, str> {
+        self.to_string_lossy()
+    }
+}
+
+impl LossyStr for OsString {
+    fn to_str_lossy(&self) -> Cow<'_, str> {
+        self.to_string_lossy()
+    }
+}
+
+impl<T: LossyStr + ?Sized> LossyStr for &T {
+    fn to_str_lossy(&self) -> Cow<'_, str> {
+        (**self).to_str_lossy()
+    }
+}
+
+impl<T: LossyStr + ?Sized> LossyStr for Box<T> {
+    fn to_str_lossy(&self) -> Cow<'_, str> {
+        (**self).to_str_lossy()
+    }
+}
+
+impl<T: LossyStr + ?Sized> LossyStr for Rc<T> {
+    fn to_str_lossy(&self) -> Cow<'_, str> {
+        (**self).to_str_lossy()
+    }
+}
+
+impl<T: LossyStr + ?Sized> LossyStr for Arc<T> {
+    fn to_str_lossy(&self) -> Cow<'_, str> {
+        (**self).to_str_lossy()
+    }
 }
 
 fn starts_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(
@@ -135,15 +978,199 @@ fn does_not_contain<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(
     }
 }
 
+fn contains_count<'s, S: DescriptiveSpec<'s>>(
+    spec: &'s S,
+    subject: &str,
+    expected: &str,
+    times: usize,
+    qualifier: &str,
+    accepts: fn(usize, usize) -> bool,
+) {
+    let count = subject.matches(expected).count();
+
+    if !accepts(count, times) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!(
+                "string containing <{:?}> {} <{}> times",
+                expected, qualifier, times
+            ))
+            .with_actual(format!("found <{}> times in <{:?}>", count, subject))
+            .fail();
+    }
+}
+
+fn contains_in_order<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, expected: &[&str]) {
+    let mut cursor = 0;
+    let mut previous: Option<(&str, usize)> = None;
+
+    for part in expected {
+        match subject[cursor..].find(part) {
+            Some(position) => {
+                previous = Some((part, cursor + position));
+                cursor += position + part.len();
+            }
+            None => {
+                let missing = match previous {
+                    Some((previous_part, index)) => format!(
+                        "<{:?}> not found after <{:?}> at index <{}>",
+                        part, previous_part, index
+                    ),
+                    None => format!("<{:?}> not found", part),
+                };
+
+                AssertionFailure::from_spec(spec)
+                    .with_expected(format!("string containing <{:?}> in order", expected))
+                    .with_actual(format!("<{:?}>, with {}", subject, missing))
+                    .fail();
+            }
+        }
+    }
+}
+
 fn is_empty<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
     if !subject.is_empty() {
         AssertionFailure::from_spec(spec)
-            .with_expected("an empty string".to_string())
-            .with_actual(format!("<{:?}>", subject))
+            .with_expected("an empty string".to_string())
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn is_equal_to_str<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, expected: &str) {
+    if let Some(details) = describe_difference(subject, expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("<{:?}>", expected))
+            .with_actual(format!("<{:?}>", subject))
+            .with_details(details)
+            .fail();
+    }
+}
+
+pub(crate) fn is_equal_normalized<'s, S: DescriptiveSpec<'s>>(
+    spec: &'s S,
+    subject: &str,
+    expected: &str,
+    normalize: fn(&str) -> String,
+    qualifier: &str,
+) {
+    let normalized_subject = normalize(subject);
+    let normalized_expected = normalize(expected);
+
+    if let Some(details) = describe_difference(&normalized_subject, &normalized_expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string equal to <{:?}> {}", expected, qualifier))
+            .with_actual(format!("<{:?}>", subject))
+            .with_details(details)
+            .fail();
+    }
+}
+
+fn contains_ignoring_case<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, expected: &str) {
+    if !subject.to_lowercase().contains(&expected.to_lowercase()) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string containing <{:?}> ignoring case", expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn starts_with_ignoring_case<'s, S: DescriptiveSpec<'s>>(
+    spec: &'s S,
+    subject: &str,
+    expected: &str,
+) {
+    if !subject.to_lowercase().starts_with(&expected.to_lowercase()) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!(
+                "string starting with <{:?}> ignoring case",
+                expected
+            ))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn matches_pattern<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, pattern: &str) {
+    let mismatch = match Pattern::parse(pattern) {
+        Ok(parsed) => parsed.describe_mismatch(subject),
+        Err(error) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("a valid pattern <{}>", pattern))
+                .with_actual(error)
+                .fail();
+
+            unreachable!();
+        }
+    };
+
+    if let Some(mismatch) = mismatch {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string matching pattern <{}>", pattern))
+            .with_actual(format!("<{:?}>, {}", subject, mismatch))
+            .fail();
+    }
+}
+
+/// The name of a type without its module path, such as `IpAddr` for `std::net::IpAddr`. Generic
+/// types keep their full name.
+fn short_type_name<T>() -> &'static str {
+    let full_name = type_name::<T>();
+
+    if full_name.contains('<') {
+        return full_name;
+    }
+
+    full_name.rsplit("::").next().unwrap_or(full_name)
+}
+
+pub(crate) fn has_length<'s, S: DescriptiveSpec<'s>>(
+    spec: &'s S,
+    subject: &str,
+    expected: usize,
+    length: usize,
+    unit: &str,
+) {
+    if length != expected {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string with length <{}> in {}", expected, unit))
+            .with_actual(format!("<{}> {} in <{:?}>", length, unit, subject))
+            .fail();
+    }
+}
+
+fn all_chars_match<'s, S: DescriptiveSpec<'s>>(
+    spec: &'s S,
+    subject: &str,
+    predicate: fn(char) -> bool,
+    description: &str,
+) {
+    if let Some((index, c)) = subject.chars().enumerate().find(|(_, c)| !predicate(*c)) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(description.to_string())
+            .with_actual(format!(
+                "<{:?}>, with char <{:?}> at index <{}>",
+                subject, c, index
+            ))
             .fail();
     }
 }
 
+fn remove_whitespace(value: &str) -> String {
+    value.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn normalize_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn trim_lines(value: &str) -> String {
+    value.lines().map(str::trim).collect::<Vec<_>>().join("\n")
+}
+
+fn normalize_newlines(value: &str) -> String {
+    value.replace("\r\n", "\n").replace('\r', "\n")
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::needless_borrows_for_generic_args)]
@@ -315,4 +1342,601 @@ mod tests {
         let value = "Hello".to_owned();
         assert_that(&value).is_empty();
     }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_to_str() {
+        assert_that(&"hello".to_string()).is_equal_to_str("hello");
+        assert_that(&&"hello").is_equal_to_str(String::from("hello"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: <\"hello world\">\n\t but was: <\"hello wurld\">\
+                   \n\tfirst difference at index <7> (line <1>, column <8>):\
+                   \n\t\texpected: hello world\
+                   \n\t\t but was: hello wurld\
+                   \n\t\t                 ^"
+    )]
+    fn should_point_at_first_difference_if_str_is_not_equal_to_str() {
+        assert_that(&"hello wurld".to_string()).is_equal_to_str("hello world");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tline diff (- expected, + actual):\
+                   \n\t\t  first\
+                   \n\t\t- second\
+                   \n\t\t+ 2nd\
+                   \n\t\t  third")]
+    fn should_show_line_diff_if_multi_line_str_is_not_equal_to_str() {
+        assert_that(&"first\n2nd\nthird").is_equal_to_str("first\nsecond\nthird");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_ignoring_case() {
+        assert_that(&"Hello World").is_equal_to_ignoring_case("hello WORLD");
+        assert_that(&"ÉCOLE".to_string()).is_equal_to_ignoring_case("école");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string equal to <\"hello there\"> ignoring case\
+                   \n\t but was: <\"Hello World\">"
+    )]
+    fn should_panic_if_str_is_not_equal_ignoring_case() {
+        assert_that(&"Hello World").is_equal_to_ignoring_case("hello there");
+    }
+
+    #[test]
+    fn should_not_panic_if_string_is_equal_ignoring_whitespace() {
+        let value = "<ul>\n  <li>one</li>\n</ul>".to_string();
+        assert_that(&value).is_equal_to_ignoring_whitespace("<ul><li>one</li></ul>");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string equal to <\"<li>two</li>\"> ignoring whitespace\
+                   \n\t but was: <\" <li>one</li> \">"
+    )]
+    fn should_panic_if_str_is_not_equal_ignoring_whitespace() {
+        assert_that(&" <li>one</li> ").is_equal_to_ignoring_whitespace("<li>two</li>");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_normalizing_whitespace() {
+        assert_that(&"  SELECT *\n\tFROM   users ")
+            .is_equal_to_normalizing_whitespace("SELECT * FROM users");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string equal to <\"SELECT * FROM users\"> \
+                   normalizing whitespace\n\t but was: <\"SELECT *FROM users\">")]
+    fn should_panic_if_whitespace_is_missing_when_normalizing_whitespace() {
+        assert_that(&"SELECT *FROM users")
+            .is_equal_to_normalizing_whitespace("SELECT * FROM users");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_ignoring_newlines() {
+        assert_that(&"first\r\nsecond\rthird")
+            .is_equal_to_ignoring_newlines("first\nsecond\nthird");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string equal to <\"first\\nsecond\"> ignoring newlines\
+                   \n\t but was: <\"first\\r\\nthird\">"
+    )]
+    fn should_panic_if_str_is_not_equal_ignoring_newlines() {
+        assert_that(&"first\r\nthird").is_equal_to_ignoring_newlines("first\nsecond");
+    }
+
+    #[test]
+    fn should_not_panic_if_string_contains_or_starts_with_value_ignoring_case() {
+        let value = "ERROR: Disk Full".to_string();
+        assert_that(&value).contains_ignoring_case("disk full");
+        assert_that(&value).starts_with_ignoring_case("error");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string containing <\"memory\"> ignoring case\
+                   \n\t but was: <\"ERROR: Disk Full\">"
+    )]
+    fn should_panic_if_str_does_not_contain_value_ignoring_case() {
+        assert_that(&"ERROR: Disk Full").contains_ignoring_case("memory");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string starting with <\"warn\"> ignoring case\
+                   \n\t but was: <\"ERROR: Disk Full\">"
+    )]
+    fn should_panic_if_str_does_not_start_with_value_ignoring_case() {
+        assert_that(&"ERROR: Disk Full").starts_with_ignoring_case("warn");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string equal to <\"<li>two</li>\"> ignoring whitespace\
+                   \n\t but was: <\"<li>\\n  one</li>\">\
+                   \n\tfirst difference at index <4> (line <1>, column <5>):\
+                   \n\t\texpected: <li>two</li>\
+                   \n\t\t but was: <li>one</li>\
+                   \n\t\t              ^"
+    )]
+    fn should_point_at_first_difference_of_normalized_strings() {
+        assert_that(&"<li>\n  one</li>").is_equal_to_ignoring_whitespace("<li>two</li>");
+    }
+
+    #[test]
+    fn should_allow_iterable_assertions_on_lines() {
+        let output = "Compiling speculoos\nRunning tests\r\nFinished".to_string();
+        let expected_lines = ["Compiling speculoos", "Running tests", "Finished"];
+
+        assert_that(&output).lines().contains("Running tests");
+        assert_that(&output)
+            .lines()
+            .equals_iterator(&expected_lines.iter());
+        assert_that(&output).lines().has_length(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [string.lines()]\
+                   \n\texpected: iterator to contain <\"Done\">\
+                   \n\t but was: <[\"Compiling\", \"Finished\"]>")]
+    fn should_name_lines_spec_after_subject() {
+        assert_that(&"Compiling\nFinished").lines().contains("Done");
+    }
+
+    #[test]
+    fn should_not_panic_if_line_count_matches_expected() {
+        assert_that(&"").has_line_count(0);
+        assert_that(&"one").has_line_count(1);
+        assert_that(&"one\ntwo\n").has_line_count(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with <3> lines\
+                   \n\t but was: <2> lines in <\"one\\ntwo\">")]
+    fn should_panic_if_line_count_does_not_match_expected() {
+        assert_that(&"one\ntwo").has_line_count(3);
+    }
+
+    #[test]
+    fn should_not_panic_if_string_contains_line() {
+        assert_that(&"one\r\ntwo".to_string()).contains_line("two");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing line <\"tw\">\
+                   \n\t but was: <[\"one\", \"two\"]>")]
+    fn should_panic_if_string_does_not_contain_line() {
+        assert_that(&"one\ntwo").contains_line("tw");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [output.line(2)]\
+                   \n\texpected: string starting with <\"one\">\n\t but was: <\"two\">")]
+    fn should_name_line_spec_after_line_number() {
+        assert_that(&"one\ntwo")
+            .named("output")
+            .line(2)
+            .starts_with("one");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with a line <0>\
+                   \n\t but was: <2> lines in <\"one\\ntwo\">")]
+    fn should_panic_if_line_number_is_zero() {
+        assert_that(&"one\ntwo").line(0);
+    }
+
+    #[test]
+    fn should_not_panic_if_string_is_equal_to_trimmed_lines() {
+        assert_that(&"  [package]  \r\n\tname = \"speculoos\"")
+            .is_equal_to_trimmed_lines("[package]\nname = \"speculoos\"");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string equal to <\"a\\nc\"> with trimmed lines\
+                   \n\t but was: <\" a\\n b \">\
+                   \n\tfirst difference at index <2> (line <2>, column <1>)"
+    )]
+    fn should_panic_if_string_is_not_equal_to_trimmed_lines() {
+        assert_that(&" a\n b ").is_equal_to_trimmed_lines("a\nc");
+    }
+
+    #[test]
+    fn should_not_panic_if_string_length_matches_expected() {
+        assert_that(&"héllo").has_length(5);
+        assert_that(&"héllo".to_string()).has_length(5);
+        assert_that(&"").has_length(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with length <6> in chars\
+                   \n\t but was: <5> chars in <\"héllo\">")]
+    fn should_panic_if_string_length_does_not_match_expected() {
+        assert_that(&"héllo".to_string()).has_length(6);
+    }
+
+    #[test]
+    fn should_not_panic_if_length_in_chars_and_bytes_match_expected() {
+        let value = "naïve café".to_string();
+        assert_that(&value).has_length_in_chars(10);
+        assert_that(&value).has_length_in_bytes(12);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with length <5> in chars\
+                   \n\t but was: <6> chars in <\"résumé\">")]
+    fn should_panic_if_length_in_chars_does_not_match_expected() {
+        assert_that(&"résumé").has_length_in_chars(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with length <6> in bytes\
+                   \n\t but was: <8> bytes in <\"résumé\">")]
+    fn should_panic_if_length_in_bytes_does_not_match_expected() {
+        assert_that(&"résumé").has_length_in_bytes(6);
+    }
+
+    #[test]
+    fn should_not_panic_if_string_chars_match_class() {
+        assert_that(&"hello, world").is_ascii();
+        assert_that(&"日本語42").is_alphanumeric();
+        assert_that(&"HÉLLO 42!").is_uppercase();
+        assert_that(&"héllo 42!").is_lowercase();
+        assert_that(&"").is_blank();
+        assert_that(&" \t\r\n".to_string()).is_blank();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an ASCII string\
+                   \n\t but was: <\"café\">, with char <'é'> at index <3>")]
+    fn should_panic_if_string_is_not_ascii() {
+        assert_that(&"café").is_ascii();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an alphanumeric string\
+                   \n\t but was: <\"abc-1\">, with char <'-'> at index <3>")]
+    fn should_panic_if_string_is_not_alphanumeric() {
+        assert_that(&"abc-1").is_alphanumeric();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an uppercase string\
+                   \n\t but was: <\"HeLLO\">, with char <'e'> at index <1>")]
+    fn should_panic_if_string_is_not_uppercase() {
+        assert_that(&"HeLLO").is_uppercase();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a lowercase string\
+                   \n\t but was: <\"hellO\">, with char <'O'> at index <4>")]
+    fn should_panic_if_string_is_not_lowercase() {
+        assert_that(&"hellO").is_lowercase();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a blank string\n\t but was: <\" . \">")]
+    fn should_panic_if_string_is_not_blank() {
+        assert_that(&" . ").is_blank();
+    }
+
+    #[test]
+    fn should_not_panic_if_string_matches_pattern() {
+        assert_that(&"request 42 took 13ms").matches_pattern("request * took *ms");
+        assert_that(&"pid 7".to_string()).matches_pattern("pid ?");
+        assert_that(&"50% *off*").matches_pattern("* \\*off\\*");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string matching pattern <request * took *ms>\
+                   \n\t but was: <\"request 42 took 13s\">, segment <\"ms\"> not found at end \
+                   after index <16>"
+    )]
+    fn should_panic_with_missing_segment_if_string_does_not_match_pattern() {
+        assert_that(&"request 42 took 13s").matches_pattern("request * took *ms");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string matching pattern <request * took *ms>\
+                   \n\t but was: <\"request 42 failed\">, segment <\" took \"> not found \
+                   after index <8>"
+    )]
+    fn should_panic_with_missing_middle_segment_if_string_does_not_match_pattern() {
+        assert_that(&"request 42 failed").matches_pattern("request * took *ms");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid pattern <abc\\>\
+                   \n\t but was: trailing escape character")]
+    fn should_panic_if_pattern_is_invalid() {
+        assert_that(&"abc").matches_pattern("abc\\");
+    }
+
+    #[test]
+    fn should_allow_assertions_on_parsed_value() {
+        assert_that(&"42").parses_as::<u32>().is_greater_than(40);
+        assert_that(&"-1.5".to_string())
+            .parses_as::<f64>()
+            .is_equal_to(-1.5);
+
+        let mut address = assert_that(&"127.0.0.1").parses_as::<std::net::Ipv4Addr>();
+        address.matches(|address| address.is_loopback());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value greater than <40>\
+                   \n\t but was: <30>")]
+    fn should_panic_if_parsed_value_does_not_match() {
+        assert_that(&"30").parses_as::<u32>().is_greater_than(40);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [port.parse::<u16>()]\
+                   \n\texpected: <80>\n\t but was: <8080>")]
+    fn should_name_parsed_value_after_subject() {
+        assert_that(&"8080")
+            .named("port")
+            .parses_as::<u16>()
+            .is_equal_to(80);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string parsing as <u32>\
+                   \n\t but was: <\"4x2\">, failing with <ParseIntError { kind: InvalidDigit }>")]
+    fn should_panic_if_string_does_not_parse() {
+        assert_that(&"4x2").parses_as::<u32>();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string parsing as <IpAddr>")]
+    fn should_panic_with_short_type_name_if_string_does_not_parse() {
+        assert_that(&"localhost").parses_as::<std::net::IpAddr>();
+    }
+
+    #[test]
+    fn should_allow_string_assertions_on_other_string_types() {
+        use std::borrow::Cow;
+        use std::ffi::{OsStr, OsString};
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        assert_that("unsized").starts_with("un");
+        assert_that(&Cow::Borrowed("borrowed")).ends_with("wed");
+        assert_that(&Box::<str>::from("boxed")).contains("ox");
+        assert_that(&Rc::<str>::from("counted")).has_length_in_chars(7);
+        assert_that(&Arc::<str>::from("shared")).is_lowercase();
+        assert_that(&'x')
+            .as_lossy_str()
+            .is_equal_to_ignoring_case("X");
+        assert_that(OsStr::new("file.txt"))
+            .as_lossy_str()
+            .ends_with(".txt");
+        assert_that(&OsString::from("one\ntwo"))
+            .as_lossy_str()
+            .has_line_count(2);
+    }
+
+    #[test]
+    fn should_allow_string_assertions_on_types_implementing_as_ref_str() {
+        struct Name(String);
+
+        impl AsRef<str> for Name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        let name = Name("Ferris".to_string());
+
+        assert_that(&name).starts_with("Fe");
+        assert_that(&name).has_line_count(1);
+        assert_that(&name).after("F").is_equal_to("erris");
+        assert_that(&name)
+            .parses_as::<String>()
+            .is_equal_to("Ferris".to_string());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[should_panic(expected = "\n\texpected: string starting with <\"caf\">\
+                   \n\t but was: <\"ca\u{fffd}\">")]
+    fn should_display_invalid_os_str_lossily() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        assert_that(OsStr::from_bytes(b"ca\xff"))
+            .as_lossy_str()
+            .starts_with("caf");
+    }
+
+    #[test]
+    fn should_allow_string_assertions_on_valid_utf8_bytes() {
+        let bytes: &[u8] = b"caf\xc3\xa9";
+
+        assert_that(&bytes).is_valid_utf8().is_equal_to("café");
+        assert_that(&b"ok".to_vec())
+            .is_valid_utf8()
+            .starts_with("o");
+        assert_that(b"lines\nof\ntext")
+            .is_valid_utf8()
+            .has_line_count(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: bytes to be valid UTF-8\
+                   \n\t but was: <b\"caf\\xe9!\">, with invalid byte sequence at index <3>")]
+    fn should_panic_if_bytes_are_not_valid_utf8() {
+        assert_that(b"caf\xe9!").is_valid_utf8();
+    }
+
+    #[test]
+    fn should_not_panic_if_string_contains_expected_number_of_times() {
+        let log = "ERROR a\nWARN b\nERROR c".to_string();
+
+        assert_that(&log).contains_times("ERROR", 2);
+        assert_that(&log).contains_times("FATAL", 0);
+        assert_that(&log).contains_at_least("ERROR", 1);
+        assert_that(&log).contains_at_least("WARN", 1);
+        assert_that(&log).contains_at_most("WARN", 1);
+        assert_that(&"aaaa").contains_times("aa", 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string containing <\"ERROR\"> exactly <1> times\
+                   \n\t but was: found <2> times in <\"ERROR a, ERROR b\">"
+    )]
+    fn should_panic_if_string_does_not_contain_exact_number_of_times() {
+        assert_that(&"ERROR a, ERROR b").contains_times("ERROR", 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string containing <\"retry\"> at least <3> times\
+                   \n\t but was: found <2> times in <\"retry, retry\">"
+    )]
+    fn should_panic_if_string_contains_fewer_times_than_expected() {
+        assert_that(&"retry, retry").contains_at_least("retry", 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string containing <\"WARN\"> at most <0> times\
+                   \n\t but was: found <1> times in <\"WARN a\">"
+    )]
+    fn should_panic_if_string_contains_more_times_than_expected() {
+        assert_that(&"WARN a").contains_at_most("WARN", 0);
+    }
+
+    #[test]
+    fn should_not_panic_if_string_contains_parts_in_order() {
+        let log = "BEGIN; do work; COMMIT".to_string();
+
+        assert_that(&log).contains_in_order(["BEGIN", "work", "COMMIT"]);
+        assert_that(&log).contains_in_order(vec!["BEGIN".to_string(), "COMMIT".to_string()]);
+        assert_that(&"abab").contains_in_order(["ab", "ab"]);
+        assert_that(&log).contains_in_order(Vec::<&str>::new());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string containing <[\"BEGIN\", \"work\", \"COMMIT\"]> \
+                   in order\n\t but was: <\"BEGIN; COMMIT; work\">, with <\"COMMIT\"> not found \
+                   after <\"work\"> at index <15>"
+    )]
+    fn should_panic_if_string_parts_are_out_of_order() {
+        assert_that(&"BEGIN; COMMIT; work").contains_in_order(["BEGIN", "work", "COMMIT"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <[\"BEGIN\"]> in order\
+                   \n\t but was: <\"COMMIT\">, with <\"BEGIN\"> not found")]
+    fn should_panic_if_first_string_part_is_missing() {
+        assert_that(&"COMMIT").contains_in_order(["BEGIN"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\texpected: string containing <[\"ab\", \"ab\"]> in order\
+                   \n\t but was: <\"aba\">, with <\"ab\"> not found after <\"ab\"> at index <0>"
+    )]
+    fn should_not_overlap_string_parts_in_order() {
+        assert_that(&"aba").contains_in_order(["ab", "ab"]);
+    }
+
+    #[test]
+    fn should_allow_assertions_on_substrings() {
+        let header = "Authorization: Bearer abc123".to_string();
+
+        assert_that(&header)
+            .strip_prefix("Authorization: ")
+            .strip_prefix("Bearer ")
+            .is_equal_to("abc123");
+        assert_that(&"main.rs")
+            .strip_suffix(".rs")
+            .is_equal_to("main");
+        assert_that(&header).after(": ").starts_with("Bearer");
+        assert_that(&header)
+            .before(":")
+            .is_equal_to("Authorization");
+        assert_that(&"a (b) (c)").between("(", ")").is_equal_to("b");
+        assert_that(&"2024-06-01").substring(5..7).is_equal_to("06");
+        assert_that(&"2024-06-01").substring(8..).is_equal_to("01");
+        assert_that(&"2024-06-01")
+            .substring(..=3)
+            .is_equal_to("2024");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [header.after(\": \")]\
+                   \n\texpected: string starting with <\"Basic\">\
+                   \n\t but was: <\"Bearer abc\">")]
+    fn should_name_substring_after_subject() {
+        assert_that(&"Authorization: Bearer abc")
+            .named("header")
+            .after(": ")
+            .starts_with("Basic");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n\tfor subject [string.strip_prefix(\"a\").between(\"(\", \")\")]\
+                   \n\texpected: <\"c\">\n\t but was: <\"b\">"
+    )]
+    fn should_name_nested_substrings() {
+        assert_that(&"a(b)")
+            .strip_prefix("a")
+            .between("(", ")")
+            .is_equal_to("c");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string starting with <\"Bearer \">\
+                   \n\t but was: <\"Basic abc\">")]
+    fn should_panic_if_prefix_to_strip_is_missing() {
+        assert_that(&"Basic abc").strip_prefix("Bearer ");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string ending with <\".rs\">\
+                   \n\t but was: <\"main.py\">")]
+    fn should_panic_if_suffix_to_strip_is_missing() {
+        assert_that(&"main.py").strip_suffix(".rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\"=\">\
+                   \n\t but was: <\"key: value\">")]
+    fn should_panic_if_marker_is_missing() {
+        assert_that(&"key: value").before("=");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\")\"> after <\"(\">\
+                   \n\t but was: <\") a (b\">")]
+    fn should_panic_if_end_marker_is_missing_after_start_marker() {
+        assert_that(&") a (b").between("(", ")");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing byte range <2..9>\
+                   \n\t but was: <\"abc\">, with length <3>")]
+    fn should_panic_if_substring_range_is_out_of_bounds() {
+        assert_that(&"abc").substring(2..9);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing byte range <0..4>\
+                   \n\t but was: <\"été\">, with index <4> not on a char boundary")]
+    fn should_panic_if_substring_range_splits_a_char() {
+        assert_that(&"été").substring(0..4);
+    }
 }
@@ -0,0 +1,81 @@
+use crate::string::{has_length, is_equal_normalized};
+use crate::Spec;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub trait UnicodeStrAssertions<T: ?Sized> {
+    #[track_caller]
+    fn has_grapheme_count(&mut self, expected: usize);
+    #[track_caller]
+    fn is_nfc_equal_to<E: AsRef<str>>(&mut self, expected: E);
+}
+
+impl<T> UnicodeStrAssertions<T> for Spec<'_, T>
+where
+    T: AsRef<str> + ?Sized,
+{
+    /// Asserts that the subject `&str` has the provided number of extended grapheme clusters,
+    /// i.e. of user-perceived characters.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"e\u{301}👍🏽").has_grapheme_count(2);
+    /// ```
+    fn has_grapheme_count(&mut self, expected: usize) {
+        let subject = self.subject.as_ref();
+        has_length(
+            self,
+            subject,
+            expected,
+            subject.graphemes(true).count(),
+            "graphemes",
+        );
+    }
+
+    /// Asserts that the subject `&str` is equal to the provided `&str` once both are converted
+    /// to Unicode Normalization Form C, so that composed and decomposed characters are equal.
+    ///
+    /// ```rust
+    /// # use speculoos::prelude::*;
+    /// assert_that(&"e\u{301}cole").is_nfc_equal_to("\u{e9}cole");
+    /// ```
+    fn is_nfc_equal_to<E: AsRef<str>>(&mut self, expected: E) {
+        let subject = self.subject.as_ref();
+        is_equal_normalized(self, subject, expected.as_ref(), to_nfc, "in NFC");
+    }
+}
+
+fn to_nfc(value: &str) -> String {
+    value.nfc().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn should_not_panic_if_grapheme_count_matches_expected() {
+        assert_that(&"e\u{301}").has_length_in_chars(2);
+        assert_that(&"e\u{301}").has_grapheme_count(1);
+        assert_that(&"🇫🇷 ok").has_grapheme_count(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with length <2> in graphemes\
+                   \n\t but was: <1> graphemes in <\"👍🏽\">")]
+    fn should_panic_if_grapheme_count_does_not_match_expected() {
+        assert_that(&"👍🏽").has_grapheme_count(2);
+    }
+
+    #[test]
+    fn should_not_panic_if_string_is_nfc_equal() {
+        assert_that(&"e\u{301}cole").is_nfc_equal_to("\u{e9}cole");
+        assert_that(&"\u{e9}cole".to_string()).is_nfc_equal_to("e\u{301}cole");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string equal to <\"ecole\"> in NFC")]
+    fn should_panic_if_string_is_not_nfc_equal() {
+        assert_that(&"e\u{301}cole").is_nfc_equal_to("ecole");
+    }
+}
@@ -1,129 +1,35 @@
-use super::{AssertionFailure, Spec};
-
-pub trait VecAssertions {
-    #[track_caller]
-    fn has_length(&mut self, expected: usize);
-    #[track_caller]
-    fn is_empty(&mut self);
-    #[track_caller]
-    fn is_not_empty(&mut self);
-}
+#[allow(deprecated)]
+pub use super::sized::VecAssertions;
 
-impl<T> VecAssertions for Spec<'_, Vec<T>> {
-    /// Asserts that the length of the subject vector is equal to the provided length. The subject
-    /// type must be of `Vec`.
-    ///
-    /// ```rust
-    /// # use speculoos::prelude::*;
-    /// assert_that!(vec![1, 2, 3, 4]).has_length(4);
-    /// ```
-    fn has_length(&mut self, expected: usize) {
-        let length = self.subject.len();
-        if length != expected {
-            AssertionFailure::from_spec(self)
-                .with_expected(format!("vec to have length <{}>", expected))
-                .with_actual(format!("<{}>", length))
-                .fail();
-        }
-    }
+#[cfg(test)]
+mod tests {
 
-    /// Asserts that the subject vector is empty. The subject type must be of `Vec`.
-    ///
-    /// ```rust
-    /// # use speculoos::prelude::*;
-    /// let test_vec: Vec<u8> = vec![];
-    /// assert_that!(test_vec).is_empty();
-    /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
-
-        if !subject.is_empty() {
-            AssertionFailure::from_spec(self)
-                .with_expected("an empty vec".to_string())
-                .with_actual(format!("a vec with length <{:?}>", subject.len()))
-                .fail();
-        }
-    }
+    use super::super::prelude::*;
 
-    /// Asserts that the subject vector is not empty. The subject type must be of `Vec`.
-    ///
-    /// ```rust
-    /// # use speculoos::prelude::*;
-    /// let test_vec: Vec<u8> = vec![1];
-    /// assert_that!(test_vec).is_not_empty();
-    /// ```
-    fn is_not_empty(&mut self) {
-        let subject = self.subject;
-
-        if subject.is_empty() {
-            AssertionFailure::from_spec(self)
-                .with_expected("a non empty vec".to_string())
-                .with_actual("an empty vec".to_string())
-                .fail();
-        }
-    }
-}
+    #[test]
+    #[allow(deprecated)]
+    fn should_keep_former_length_trait_bounds_compiling() {
+        use std::collections::{HashMap, HashSet};
 
-impl<'s, T> VecAssertions for Spec<'s, &'s Vec<T>> {
-    /// Asserts that the length of the subject vector is equal to the provided length. The subject
-    /// type must be of `&Vec` with a matching lifetime.
-    ///
-    /// ```rust
-    /// # use speculoos::prelude::*;
-    /// assert_that(&&vec![1, 2, 3, 4]).has_length(4);
-    /// ```
-    fn has_length(&mut self, expected: usize) {
-        let length = self.subject.len();
-        if length != expected {
-            AssertionFailure::from_spec(self)
-                .with_expected(format!("vec to have length <{}>", expected))
-                .with_actual(format!("<{}>", length))
-                .fail();
+        fn check_vec<T: VecAssertions>(spec: &mut T) {
+            spec.has_length(1);
         }
-    }
 
-    /// Asserts that the subject vector is empty. The subject type must be of `&Vec` with a
-    /// matching lifetime.
-    ///
-    /// ```rust
-    /// # use speculoos::prelude::*;
-    /// let test_vec: &Vec<u8> = &vec![];
-    /// assert_that(&test_vec).is_empty();
-    /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
-
-        if !subject.is_empty() {
-            AssertionFailure::from_spec(self)
-                .with_expected("an empty vec".to_string())
-                .with_actual(format!("a vec with length <{:?}>", subject.len()))
-                .fail();
+        fn check_map<'s, T: HashMapAssertions<'s>>(spec: &mut T) {
+            spec.is_not_empty();
         }
-    }
 
-    /// Asserts that the subject vector is not empty. The subject type must be of `&Vec` with a
-    /// matching lifetime.
-    /// ```rust
-    /// # use speculoos::prelude::*;
-    /// let test_vec: Vec<u8> = vec![1];
-    /// assert_that(&test_vec).is_not_empty();
-    /// ```
-    fn is_not_empty(&mut self) {
-        let subject = self.subject;
-
-        if subject.is_empty() {
-            AssertionFailure::from_spec(self)
-                .with_expected("a non empty vec".to_string())
-                .with_actual("an empty vec".to_string())
-                .fail();
+        fn check_set<'s, T: HashSetAssertions<'s>>(spec: &mut T) {
+            spec.has_length(1);
         }
-    }
-}
 
-#[cfg(test)]
-mod tests {
+        let test_map: HashMap<u8, u8> = [(1, 1)].into_iter().collect();
+        let test_set: HashSet<u8> = [1].into_iter().collect();
 
-    use super::super::prelude::*;
+        check_vec(&mut assert_that(&vec![1]));
+        check_map(&mut assert_that(&test_map));
+        check_set(&mut assert_that(&test_set));
+    }
 
     #[test]
     fn should_not_panic_if_vec_length_matches_expected() {